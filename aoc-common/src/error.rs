@@ -0,0 +1,156 @@
+//! Parse errors with a position in the input.
+
+use std::error;
+use std::fmt;
+
+/// Error produced by a parser, pointing at the offending place in the input.
+///
+/// Lines and columns are 1-based and count characters, not bytes.
+/// Positions are relative to the text given to the parser; use [`ParseError::locate`]
+/// to move an error found in a part of the input (e.g. a single line) into the whole input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error pointing at `at`, which must be a subslice of `text`.
+    ///
+    /// If `at` does not belong to `text`, the error points at the start of `text`.
+    pub fn at(text: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(text, at).unwrap_or(0);
+        let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line = text[..offset].matches('\n').count() + 1;
+        let column = text[line_start..offset].chars().count() + 1;
+        ParseError {
+            file: None,
+            line,
+            column,
+            snippet: snippet_at(text, line),
+            message: message.into(),
+        }
+    }
+
+    /// Converts any error raised while parsing `text` (a subslice of `input`)
+    /// into a [`ParseError`] relative to the whole `input`.
+    ///
+    /// Errors which are not [`ParseError`]s are reported at the start of `text`.
+    pub fn locate(err: impl Into<anyhow::Error>, input: &str, text: &str) -> Self {
+        let err = err.into();
+        let start = ParseError::at(input, text, "");
+        match err.downcast::<ParseError>() {
+            Ok(e) => {
+                let line = start.line + e.line - 1;
+                let column = if e.line == 1 {
+                    start.column + e.column - 1
+                } else {
+                    e.column
+                };
+                ParseError {
+                    file: e.file,
+                    line,
+                    column,
+                    snippet: snippet_at(input, line),
+                    message: e.message,
+                }
+            }
+            Err(e) => ParseError {
+                message: format!("{:#}", e),
+                ..start
+            },
+        }
+    }
+
+    /// Sets the name of the file the input was read from.
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+/// Attaches the `file` name to the error if it is a [`ParseError`].
+pub fn with_file(err: anyhow::Error, file: &str) -> anyhow::Error {
+    match err.downcast::<ParseError>() {
+        Ok(e) => e.with_file(file).into(),
+        Err(e) => e,
+    }
+}
+
+fn offset_in(text: &str, at: &str) -> Option<usize> {
+    let start = text.as_ptr() as usize;
+    let ptr = at.as_ptr() as usize;
+    if (start..=start + text.len()).contains(&ptr) {
+        Some(ptr - start)
+    } else {
+        None
+    }
+}
+
+fn snippet_at(text: &str, line: usize) -> String {
+    text.lines()
+        .nth(line - 1)
+        .unwrap_or_default()
+        .trim_end()
+        .to_string()
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let text = "FBFBBFFRLR\nFBFZBFFRLR\n";
+        let e = ParseError::at(text, &text[14..], "Bad char `Z`");
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.snippet, "FBFZBFFRLR");
+    }
+
+    #[test]
+    fn test_locate() {
+        let input = "1-3 a: abcde\n1-3 b cdefg\n";
+        let line = input.lines().nth(1).unwrap();
+        let e = ParseError::at(line, &line[5..], "Expected `:`");
+        let e = ParseError::locate(e, input, line);
+        assert_eq!((e.line, e.column), (2, 6));
+        assert_eq!(e.snippet, "1-3 b cdefg");
+    }
+
+    #[test]
+    fn test_locate_foreign_error() {
+        let input = "12\nxy\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = line.parse::<i32>().unwrap_err();
+        let e = ParseError::locate(err, input, line);
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.message, "invalid digit found in string");
+    }
+
+    #[test]
+    fn test_display() {
+        let e =
+            ParseError::at("ab\ncd", &"ab\ncd"[4..], "Bad char `d`").with_file("data/input.txt");
+        assert_eq!(
+            e.to_string(),
+            "Bad char `d`\n --> data/input.txt:2:2\n  |\n2 | cd\n  |  ^"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! Helpers for reading and splitting puzzle inputs.
+
+use std::fs;
+use std::io::{self, Read};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use crate::ParseError;
+
+/// Reads the whole input from the file at `path`, or from stdin if `path` is `-`.
+pub fn read_input(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("Could not read input `{}`", path))
+    }
+}
+
+/// Returns the `input` if it has any content, or a [`ParseError`] for an empty
+/// (e.g. truncated) input, which no puzzle can be solved on.
+pub fn non_empty(input: &str) -> Result<&str, ParseError> {
+    if input.trim().is_empty() {
+        Err(ParseError::at(input, input, "Empty input"))
+    } else {
+        Ok(input)
+    }
+}
+
+/// Parses each line of the `input` into `T`.
+///
+/// Errors are reported as [`ParseError`]s pointing at the offending line.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    parse_lines_with(input, str::parse)
+}
+
+/// Parses each line of the `input` using the function `f`.
+///
+/// Errors are reported as [`ParseError`]s pointing at the offending line.
+pub fn parse_lines_with<T, E, F>(input: &str, mut f: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T, E>,
+    E: Into<anyhow::Error>,
+{
+    input
+        .lines()
+        .map(|line| f(line).map_err(|e| ParseError::locate(e, input, line).into()))
+        .collect()
+}
+
+/// Parses comma-separated values, e.g. `0,3,6`.
+///
+/// Errors are reported as [`ParseError`]s pointing at the offending value.
+pub fn parse_csv<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    s.split(',')
+        .map(|x| {
+            let x = x.trim();
+            x.parse().map_err(|e| ParseError::locate(e, s, x).into())
+        })
+        .collect()
+}
+
+/// Iterator over groups of consecutive non-blank lines, see [`groups`].
+pub struct Groups<I> {
+    lines: I,
+}
+
+impl<I> Iterator for Groups<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group = Vec::new();
+        for line in self.lines.by_ref() {
+            if line.as_ref().trim().is_empty() {
+                if group.is_empty() {
+                    // Skip leading and repeated blank lines
+                    continue;
+                }
+                return Some(group);
+            }
+            group.push(line);
+        }
+        // Note: the last group is not followed by a blank line
+        if group.is_empty() {
+            None
+        } else {
+            Some(group)
+        }
+    }
+}
+
+/// Groups the `lines` into blank-line separated records.
+///
+/// Works with any iterator over lines, e.g. `str::lines` or `BufRead::lines`
+/// (after the errors are handled).
+pub fn groups<I>(lines: I) -> Groups<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Groups {
+        lines: lines.into_iter(),
+    }
+}
+
+/// Splits the `input` into blank-line separated sections.
+///
+/// Each section is a slice of the `input` without the trailing newline.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(s) = start.take() {
+                sections.push(&input[s..end]);
+            }
+        } else {
+            if start.is_none() {
+                start = Some(offset);
+            }
+            end = offset + line.trim_end_matches(&['\r', '\n'][..]).len();
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start {
+        sections.push(&input[s..end]);
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let data: Vec<i32> = parse_lines("1\n-2\n3\n").unwrap();
+        assert_eq!(data, vec![1, -2, 3]);
+    }
+
+    #[test]
+    fn test_parse_lines_error() {
+        let e = parse_lines::<i32>("1\nx\n3").unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.snippet, "x");
+    }
+
+    #[test]
+    fn test_non_empty() {
+        assert_eq!(non_empty("1\n").unwrap(), "1\n");
+        let e = non_empty(" \n").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.message.as_str()),
+            (1, 1, "Empty input")
+        );
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let data: Vec<usize> = parse_csv("0,3, 6").unwrap();
+        assert_eq!(data, vec![0, 3, 6]);
+        let e = parse_csv::<usize>("0,3,x6").unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (1, 5));
+    }
+
+    #[test]
+    fn test_groups() {
+        let input = "\nabc\n\na\nb\nc\n\n\nab\nac\n";
+        let groups = groups(input.lines()).collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            vec![vec!["abc"], vec!["a", "b", "c"], vec!["ab", "ac"]]
+        );
+    }
+
+    #[test]
+    fn test_groups_last_without_blank_line() {
+        let groups = groups("a\n\nb\nc".lines()).collect::<Vec<_>>();
+        assert_eq!(groups, vec![vec!["a"], vec!["b", "c"]]);
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "rules\nmore rules\n\nyour ticket:\n1,2\r\n\r\nnearby:\n3,4\n";
+        assert_eq!(
+            sections(input),
+            vec!["rules\nmore rules", "your ticket:\n1,2", "nearby:\n3,4"]
+        );
+    }
+}
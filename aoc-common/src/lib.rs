@@ -0,0 +1,9 @@
+//! Common code shared by all days.
+
+pub use crate::error::{with_file, ParseError};
+pub use crate::solution::{solve, Answer, Part, Solution, Solved};
+
+mod error;
+pub mod input;
+pub mod logging;
+mod solution;
@@ -0,0 +1,174 @@
+//! Leveled logging for diagnostic output of the solvers.
+//!
+//! Days log through the `log` macros; the messages go to stderr.
+//! Verbosity is given by a spec like `info` or `quiet,day11=debug,day14=trace`:
+//! a default level followed by optional per-day (per-crate) overrides.
+
+use std::env;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Environment variable with the verbosity spec used by [`init_from_env`].
+pub const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only warnings and errors
+    Quiet,
+    /// Progress and intermediate results
+    Info,
+    /// Dumps of parsed data and solver state
+    Debug,
+    /// Per-step traces, very noisy
+    Trace,
+}
+
+impl Verbosity {
+    fn level_filter(self) -> LevelFilter {
+        match self {
+            Verbosity::Quiet => LevelFilter::Warn,
+            Verbosity::Info => LevelFilter::Info,
+            Verbosity::Debug => LevelFilter::Debug,
+            Verbosity::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+impl FromStr for Verbosity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "quiet" => Ok(Verbosity::Quiet),
+            "info" => Ok(Verbosity::Info),
+            "debug" => Ok(Verbosity::Debug),
+            "trace" => Ok(Verbosity::Trace),
+            _ => Err(anyhow!(
+                "Bad verbosity `{}`, expected `quiet`, `info`, `debug` or `trace`",
+                s
+            )),
+        }
+    }
+}
+
+/// Verbosity with per-target overrides, e.g. `info,day11=debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Verbosity,
+    targets: Vec<(String, Verbosity)>,
+}
+
+impl Filter {
+    pub fn new(default: Verbosity) -> Self {
+        Filter {
+            default,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Returns the verbosity for the log `target` (a module path like `day11::grid`).
+    pub fn verbosity(&self, target: &str) -> Verbosity {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(prefix, _)| target == prefix || target.starts_with(&format!("{}::", prefix)))
+            .map_or(self.default, |&(_, v)| v)
+    }
+
+    fn max_verbosity(&self) -> Verbosity {
+        self.targets
+            .iter()
+            .map(|&(_, v)| v)
+            .fold(self.default, Verbosity::max)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::new(Verbosity::Info);
+        for item in s.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+            match item.split_once('=') {
+                Some((target, verbosity)) => {
+                    filter
+                        .targets
+                        .push((target.trim().to_string(), verbosity.trim().parse()?));
+                }
+                None => filter.default = item.parse()?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+struct Logger {
+    filter: Filter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= self.filter.verbosity(metadata.target()).level_filter()
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        match record.level() {
+            Level::Info => eprintln!("{}", record.args()),
+            level => eprintln!(
+                "[{}] [{}] {}",
+                level.as_str().to_lowercase(),
+                record.target(),
+                record.args()
+            ),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the logger with the given `filter`.
+///
+/// Without a logger (e.g. in tests) all diagnostic output is suppressed.
+pub fn init(filter: Filter) -> Result<()> {
+    let max_level = filter.max_verbosity().level_filter();
+    log::set_boxed_logger(Box::new(Logger { filter }))?;
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+/// Installs the logger with the filter from the [`ENV_VAR`] variable (`info` by default).
+pub fn init_from_env() -> Result<()> {
+    let filter = match env::var(ENV_VAR) {
+        Ok(spec) => spec.parse()?,
+        Err(_) => Filter::new(Verbosity::Info),
+    };
+    init(filter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter: Filter = "quiet,day11=debug,day14=trace".parse().unwrap();
+        assert_eq!(filter.verbosity("day01"), Verbosity::Quiet);
+        assert_eq!(filter.verbosity("day11"), Verbosity::Debug);
+        assert_eq!(filter.verbosity("day14::mask"), Verbosity::Trace);
+        assert_eq!(filter.verbosity("day1"), Verbosity::Quiet);
+        assert_eq!(filter.max_verbosity(), Verbosity::Trace);
+    }
+
+    #[test]
+    fn test_filter_default() {
+        let filter: Filter = "day05=quiet".parse().unwrap();
+        assert_eq!(filter.verbosity("day04"), Verbosity::Info);
+        assert_eq!(filter.verbosity("day05"), Verbosity::Quiet);
+        assert!("loud".parse::<Filter>().is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+/// Puzzle part.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part{}", self.number())
+    }
+}
+
+/// Solution for a single day.
+///
+/// The input is parsed once with [`Solution::parse`],
+/// and then both parts are solved using the parsed input.
+pub trait Solution {
+    type Input;
+    type AnswerOne: fmt::Display;
+    type AnswerTwo: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::AnswerOne>;
+    fn part_two(input: &Self::Input) -> Result<Self::AnswerTwo>;
+}
+
+/// Rendered answer for a single part.
+///
+/// Parts are solved independently, so a failure of one part
+/// does not prevent the other one from being reported.
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub value: Result<String>,
+    /// Time spent solving the part, excluding parsing.
+    pub elapsed: Duration,
+}
+
+/// Answers for the solved parts along with the time spent parsing the input.
+#[derive(Debug)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Parses the `input` and solves the given `parts` using the solution `S`.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Solved> {
+    let start_time = Instant::now();
+    let data = S::parse(input)?;
+    let parse_time = start_time.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start_time = Instant::now();
+            let value = match part {
+                Part::One => S::part_one(&data).map(|x| x.to_string()),
+                Part::Two => S::part_two(&data).map(|x| x.to_string()),
+            };
+            let elapsed = start_time.elapsed();
+            Answer {
+                part,
+                value,
+                elapsed,
+            }
+        })
+        .collect();
+
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
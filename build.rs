@@ -0,0 +1,38 @@
+//! Generates a test for each case registered in `answers.txt`.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+#[path = "src/registry.rs"]
+#[allow(dead_code)]
+mod registry;
+
+fn main() {
+    println!("cargo:rerun-if-changed=answers.txt");
+    println!("cargo:rerun-if-changed=src/registry.rs");
+
+    let registry = fs::read_to_string("answers.txt").expect("Could not read answers.txt");
+    let cases = registry::parse(&registry).unwrap_or_else(|e| panic!("{}", e));
+
+    let mut tests = String::new();
+    for case in cases.iter() {
+        let stem = case
+            .input
+            .trim_end_matches(".txt")
+            .replace(|c: char| !c.is_alphanumeric(), "_");
+        let name = format!("day{:02}_part{}_{}", case.day, case.part, stem);
+        if case.slow {
+            writeln!(tests, "#[ignore]").unwrap();
+        }
+        writeln!(
+            tests,
+            "#[test]\nfn {}() {{\n    check({}, {}, {:?}, {:?});\n}}\n",
+            name, case.day, case.part, case.input, case.answer
+        )
+        .unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("registry_tests.rs"), tests).unwrap();
+}
@@ -0,0 +1,283 @@
+//! Search for `k` entries summing up to the given target.
+
+use std::collections::BTreeMap;
+use std::ops::ControlFlow;
+
+/// Combination of entries, given by their (ascending) indices in the original data.
+pub type Combination = Vec<usize>;
+
+/// Index over the entries, sorted by value, which answers k-sum queries.
+///
+/// Pairs are found with two pointers over the sorted entries,
+/// larger `k` are reduced to pairs, pruning the prefixes which
+/// can not reach the target even with the smallest or the largest entries.
+#[derive(Debug)]
+pub struct KSum {
+    /// Indices of the entries in the order of their values
+    order: Vec<usize>,
+    /// Sorted values
+    sorted: Vec<i128>,
+    /// Prefix sums of the sorted values
+    prefix: Vec<i128>,
+}
+
+impl KSum {
+    pub fn new(values: &[i64]) -> Self {
+        let mut order = (0..values.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| values[i]);
+        let sorted = order.iter().map(|&i| values[i] as i128).collect::<Vec<_>>();
+        let prefix = std::iter::once(0)
+            .chain(sorted.iter().scan(0, |s, &x| {
+                *s += x;
+                Some(*s)
+            }))
+            .collect();
+        KSum {
+            order,
+            sorted,
+            prefix,
+        }
+    }
+
+    /// Returns some combination of `k` entries summing up to the `target`.
+    pub fn first(&self, k: usize, target: i64) -> Option<Combination> {
+        let mut found = None;
+        self.for_each(k, target, |c| {
+            found = Some(c.to_vec());
+            ControlFlow::Break(())
+        });
+        found
+    }
+
+    /// Returns all combinations of `k` entries summing up to the `target`.
+    pub fn all(&self, k: usize, target: i64) -> Vec<Combination> {
+        let mut found = Vec::new();
+        self.for_each(k, target, |c| {
+            found.push(c.to_vec());
+            ControlFlow::Continue(())
+        });
+        found.sort();
+        found
+    }
+
+    /// Calls `f` for each combination of `k` entries summing up to the `target`,
+    /// until it breaks.
+    pub fn for_each<F>(&self, k: usize, target: i64, mut f: F)
+    where
+        F: FnMut(&[usize]) -> ControlFlow<()>,
+    {
+        let mut stack = Vec::with_capacity(k);
+        let _ = self.search(k, 0, target as i128, &mut stack, &mut f);
+    }
+
+    fn search(
+        &self,
+        k: usize,
+        start: usize,
+        target: i128,
+        stack: &mut Vec<usize>,
+        f: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let n = self.sorted.len();
+        if n - start < k {
+            return ControlFlow::Continue(());
+        }
+        match k {
+            0 if target == 0 => self.emit(stack, &[], f),
+            0 => ControlFlow::Continue(()),
+            1 => {
+                let lo = start + self.sorted[start..].partition_point(|&x| x < target);
+                let hi = start + self.sorted[start..].partition_point(|&x| x <= target);
+                for i in lo..hi {
+                    self.emit(stack, &[i], f)?;
+                }
+                ControlFlow::Continue(())
+            }
+            2 => self.search_pairs(start, target, stack, f),
+            _ => {
+                let largest = self.prefix[n] - self.prefix[n - (k - 1)];
+                for i in start..=n - k {
+                    // Note: even the smallest remaining entries overshoot
+                    if self.prefix[i + k] - self.prefix[i] > target {
+                        break;
+                    }
+                    // Note: even the largest entries do not reach the target
+                    if self.sorted[i] + largest < target {
+                        continue;
+                    }
+                    stack.push(i);
+                    let flow = self.search(k - 1, i + 1, target - self.sorted[i], stack, f);
+                    stack.pop();
+                    flow?;
+                }
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    fn search_pairs(
+        &self,
+        start: usize,
+        target: i128,
+        stack: &[usize],
+        f: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let xs = &self.sorted;
+        let (mut lo, mut hi) = (start, xs.len() - 1);
+        while lo < hi {
+            let sum = xs[lo] + xs[hi];
+            if sum < target {
+                lo += 1;
+            } else if sum > target {
+                hi -= 1;
+            } else if xs[lo] == xs[hi] {
+                // All entries in `lo..=hi` are equal, any two of them make a pair
+                for i in lo..hi {
+                    for j in i + 1..=hi {
+                        self.emit(stack, &[i, j], f)?;
+                    }
+                }
+                break;
+            } else {
+                let lo_end = lo + xs[lo..].iter().take_while(|&&x| x == xs[lo]).count();
+                let hi_start =
+                    hi + 1 - xs[..=hi].iter().rev().take_while(|&&x| x == xs[hi]).count();
+                for i in lo..lo_end {
+                    for j in hi_start..=hi {
+                        self.emit(stack, &[i, j], f)?;
+                    }
+                }
+                lo = lo_end;
+                hi = hi_start - 1;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Passes the combination of sorted positions `stack ++ rest` to `f` as original indices.
+    fn emit(
+        &self,
+        stack: &[usize],
+        rest: &[usize],
+        f: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let mut indices = stack
+            .iter()
+            .chain(rest)
+            .map(|&i| self.order[i])
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        f(&indices)
+    }
+}
+
+/// Combinations of entries sharing the same multiset of values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueGroup {
+    /// Sorted values of the entries
+    pub values: Vec<i64>,
+    /// Combinations of indices having these values
+    pub combinations: Vec<Combination>,
+}
+
+impl ValueGroup {
+    pub fn count(&self) -> usize {
+        self.combinations.len()
+    }
+}
+
+/// Groups the `combinations` of entries by their multisets of values (in ascending order).
+pub fn group_by_values(values: &[i64], combinations: &[Combination]) -> Vec<ValueGroup> {
+    let mut groups = BTreeMap::<Vec<i64>, Vec<Combination>>::new();
+    for c in combinations {
+        let mut key = c.iter().map(|&i| values[i]).collect::<Vec<_>>();
+        key.sort_unstable();
+        groups.entry(key).or_default().push(c.clone());
+    }
+    groups
+        .into_iter()
+        .map(|(values, combinations)| ValueGroup {
+            values,
+            combinations,
+        })
+        .collect()
+}
+
+/// Returns the product of the entries at `indices`, or `None` on overflow.
+pub fn checked_product(values: &[i64], indices: &[usize]) -> Option<i64> {
+    indices
+        .iter()
+        .try_fold(1i64, |acc, &i| acc.checked_mul(values[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    const SAMPLE: [i64; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    fn brute_force(values: &[i64], k: usize, target: i64) -> Vec<Combination> {
+        (0..values.len())
+            .combinations(k)
+            .filter(|c| c.iter().map(|&i| values[i]).sum::<i64>() == target)
+            .collect()
+    }
+
+    #[test]
+    fn test_sample() {
+        let ksum = KSum::new(&SAMPLE);
+        assert_eq!(ksum.first(2, 2020), Some(vec![0, 3]));
+        assert_eq!(ksum.first(3, 2020), Some(vec![1, 2, 4]));
+        assert_eq!(checked_product(&SAMPLE, &[0, 3]), Some(514579));
+        assert_eq!(checked_product(&SAMPLE, &[1, 2, 4]), Some(241861950));
+        assert_eq!(ksum.first(2, 7), None);
+    }
+
+    #[test]
+    fn test_all_matches_brute_force() {
+        let values = [5, -1, 3, 3, 0, 2, 5, 1, 3, -2, 4, 2];
+        let ksum = KSum::new(&values);
+        for k in 0..=5 {
+            for target in -3..=15 {
+                assert_eq!(
+                    ksum.all(k, target),
+                    brute_force(&values, k, target),
+                    "k = {}, target = {}",
+                    k,
+                    target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_group_by_values() {
+        let values = [1010, 1000, 1010, 1020, 1010];
+        let ksum = KSum::new(&values);
+        let all = ksum.all(2, 2020);
+        assert_eq!(all, vec![vec![0, 2], vec![0, 4], vec![1, 3], vec![2, 4]]);
+        let groups = group_by_values(&values, &all);
+        assert_eq!(
+            groups,
+            vec![
+                ValueGroup {
+                    values: vec![1000, 1020],
+                    combinations: vec![vec![1, 3]],
+                },
+                ValueGroup {
+                    values: vec![1010, 1010],
+                    combinations: vec![vec![0, 2], vec![0, 4], vec![2, 4]],
+                },
+            ]
+        );
+        assert_eq!(groups[1].count(), 3);
+    }
+
+    #[test]
+    fn test_checked_product_overflow() {
+        let values = [i64::MAX, 2];
+        assert_eq!(checked_product(&values, &[0, 1]), None);
+    }
+}
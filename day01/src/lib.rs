@@ -0,0 +1,97 @@
+use anyhow::{anyhow, Result};
+use log::{debug, info, log_enabled, Level};
+
+use aoc_common::input::parse_lines;
+use aoc_common::Solution;
+
+pub use crate::ksum::{checked_product, group_by_values, Combination, KSum, ValueGroup};
+
+mod ksum;
+
+/// Sum of the entries we are looking for in the expense report.
+pub const TARGET: i64 = 2020;
+
+/// All combinations of entries summing up to the target.
+#[derive(Debug)]
+pub struct Matches {
+    /// Distinct combinations of indices
+    pub combinations: Vec<Combination>,
+    /// Combinations grouped by the multisets of their values
+    pub groups: Vec<ValueGroup>,
+}
+
+/// Enumerates all combinations of `k` entries summing up to the `target`.
+pub fn find_all(data: &[i64], k: usize, target: i64) -> Matches {
+    let combinations = KSum::new(data).all(k, target);
+    let groups = group_by_values(data, &combinations);
+    Matches {
+        combinations,
+        groups,
+    }
+}
+
+/// Finds `k` entries summing up to the `target` and returns their product.
+///
+/// When several combinations match, any one of them is used;
+/// with debug logging, all of them are enumerated and reported.
+pub fn solve(data: &[i64], k: usize, target: i64) -> Result<i64> {
+    if log_enabled!(Level::Debug) {
+        log_matches(&find_all(data, k, target), k, target);
+    }
+
+    let combination = KSum::new(data)
+        .first(k, target)
+        .ok_or_else(|| anyhow!("No {} entries sum to {}", k, target))?;
+    let values = combination.iter().map(|&i| data[i]).collect::<Vec<_>>();
+    let product = checked_product(data, &combination)
+        .ok_or_else(|| anyhow!("Product of {:?} overflows", values))?;
+    info!("Product of {:?} = {}", values, product);
+    Ok(product)
+}
+
+fn log_matches(matches: &Matches, k: usize, target: i64) {
+    debug!(
+        "Found {} combination(s) of {} entries summing to {}, with {} distinct value set(s)",
+        matches.combinations.len(),
+        k,
+        target,
+        matches.groups.len()
+    );
+    for group in matches.groups.iter() {
+        debug!(
+            " - {:?} x{} at {:?}",
+            group.values,
+            group.count(),
+            group.combinations
+        );
+    }
+    if matches.groups.len() > 1 {
+        debug!(
+            "Expense report is ambiguous: {} value sets sum to {}",
+            matches.groups.len(),
+            target
+        );
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input)
+    }
+
+    fn part_one(data: &Self::Input) -> Result<Self::AnswerOne> {
+        info!("Solving part one...");
+        solve(data, 2, TARGET)
+    }
+
+    fn part_two(data: &Self::Input) -> Result<Self::AnswerTwo> {
+        info!("Solving part two...");
+        solve(data, 3, TARGET)
+    }
+}
@@ -1,47 +1,53 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::env;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+
+use aoc_common::input::read_input;
+use aoc_common::{logging, with_file, Solution};
+use day01::{find_all, Day01, TARGET};
+
+const USAGE: &str = "Usage: day01 [--all]";
 
 fn main() -> Result<()> {
-    let path = "data/input.txt";
-    let data: Vec<_> = BufReader::new(File::open(path)?)
-        .lines()
-        .filter_map(|x| x.ok())
-        .map(|line| line.parse::<i32>().unwrap())
-        .collect();
-
-    println!("Solving part one...");
-    'outer1: for i in 0..data.len() {
-        for j in (i + 1)..data.len() {
-            let a = data[i];
-            let b = data[j];
-            if a + b == 2020 {
-                println!("Found!");
-                println!("{} + {} = 2020", a, b);
-                println!("{} * {} = {}", a, b, a * b);
-                break 'outer1;
-            }
+    logging::init_from_env()?;
+
+    let mut show_all = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--all" => show_all = true,
+            _ => bail!(USAGE),
         }
     }
 
-    println!("===================");
-    println!("Solving part two...");
-    'outer2: for i in 0..data.len() {
-        for j in (i + 1)..data.len() {
-            for k in (j + 1)..data.len() {
-                let a = data[i];
-                let b = data[j];
-                let c = data[k];
-                if a + b + c == 2020 {
-                    println!("Found!");
-                    println!("{} + {} + {} = 2020", a, b, c);
-                    println!("{} * {} * {} = {}", a, b, c, a * b * c);
-                    break 'outer2;
-                }
+    let path = "data/input.txt";
+    let input = read_input(path)?;
+    let data = Day01::parse(&input).map_err(|e| with_file(e, path))?;
+
+    if show_all {
+        for k in [2, 3].iter().copied() {
+            let matches = find_all(&data, k, TARGET);
+            println!(
+                "[k={}] {} combination(s), {} distinct value set(s)",
+                k,
+                matches.combinations.len(),
+                matches.groups.len()
+            );
+            for group in matches.groups.iter() {
+                println!(
+                    "[k={}] {:?} x{} at {:?}",
+                    k,
+                    group.values,
+                    group.count(),
+                    group.combinations
+                );
             }
         }
+        return Ok(());
     }
 
+    println!("[part1] {}", Day01::part_one(&data)?);
+    println!("===================");
+    println!("[part2] {}", Day01::part_two(&data)?);
+
     Ok(())
 }
@@ -0,0 +1,299 @@
+//! Generation of valid and invalid passwords for the classic policies.
+//!
+//! Used to build fixtures for policy engines and to fuzz [`Params::is_valid_first`]
+//! and [`Params::is_valid_second`] against the declarative [`Policy`](crate::Policy).
+
+use rand::rngs::StdRng;
+use rand::seq::index;
+use rand::{Rng, SeedableRng};
+
+use crate::policy::{Params, Template};
+
+/// Interpretation of the `<min>-<max> <letter>` parameters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Scheme {
+    /// `letter` occurs from `min` to `max` times
+    SledRental,
+    /// `letter` is at exactly one of the positions `min` and `max`
+    Toboggan,
+}
+
+impl Scheme {
+    pub fn template(&self) -> Template {
+        let spec = match self {
+            Scheme::SledRental => Template::SLED_RENTAL,
+            Scheme::Toboggan => Template::TOBOGGAN,
+        };
+        Template::new(spec).unwrap()
+    }
+
+    /// Ways in which a password may violate the policy.
+    pub fn violations(&self) -> &'static [Violation] {
+        match self {
+            Scheme::SledRental => &[Violation::TooFew, Violation::TooMany],
+            Scheme::Toboggan => &[Violation::NoPosition, Violation::BothPositions],
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// Less than `min` occurrences of the letter
+    TooFew,
+    /// More than `max` occurrences of the letter
+    TooMany,
+    /// The letter is at neither of the positions
+    NoPosition,
+    /// The letter is at both positions
+    BothPositions,
+}
+
+/// Password which is checked differently by the tested function and by the policy engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    pub params: Params,
+    pub password: String,
+    /// Verdict of the policy engine
+    pub expected: bool,
+}
+
+pub struct Generator<R> {
+    rng: R,
+    alphabet: Vec<char>,
+    /// Upper bound for the random parameters and for the extra length of passwords
+    max_len: usize,
+}
+
+impl Generator<StdRng> {
+    /// Returns a reproducible generator over lowercase letters.
+    pub fn seeded(seed: u64) -> Self {
+        Generator::new(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl<R: Rng> Generator<R> {
+    pub fn new(rng: R) -> Self {
+        Generator {
+            rng,
+            alphabet: ('a'..='z').collect(),
+            max_len: 20,
+        }
+    }
+
+    /// Returns random parameters with `1 <= min <= max`.
+    pub fn params(&mut self) -> Params {
+        let letter = self.letter();
+        let min = self.rng.gen_range(1..=self.max_len);
+        let max = self.rng.gen_range(min..=self.max_len);
+        Params { letter, min, max }
+    }
+
+    /// Returns a password satisfying the policy, if there is one.
+    pub fn valid(&mut self, scheme: Scheme, params: &Params) -> Option<String> {
+        match scheme {
+            Scheme::SledRental if params.min <= params.max => {
+                let count = self.rng.gen_range(params.min..=params.max);
+                Some(self.with_count(params.letter, count))
+            }
+            Scheme::SledRental => None,
+            Scheme::Toboggan => {
+                let first = self.rng.gen();
+                self.with_positions(params, first, !first)
+                    .or_else(|| self.with_positions(params, !first, first))
+            }
+        }
+    }
+
+    /// Returns a password violating the policy in the given way, if there is one.
+    pub fn invalid(&mut self, params: &Params, violation: Violation) -> Option<String> {
+        match violation {
+            Violation::TooFew if params.min > 0 => {
+                let count = self.rng.gen_range(0..params.min);
+                Some(self.with_count(params.letter, count))
+            }
+            Violation::TooFew => None,
+            Violation::TooMany => {
+                let count = self
+                    .rng
+                    .gen_range(params.max + 1..=params.max + self.max_len);
+                Some(self.with_count(params.letter, count))
+            }
+            Violation::NoPosition => self.with_positions(params, false, false),
+            Violation::BothPositions => self.with_positions(params, true, true),
+        }
+    }
+
+    /// Returns fixtures for the policy: passwords with their expected verdicts.
+    pub fn examples(&mut self, scheme: Scheme, params: &Params) -> Vec<(String, bool)> {
+        let mut examples = Vec::new();
+        examples.extend(self.valid(scheme, params).map(|p| (p, true)));
+        for &violation in scheme.violations() {
+            examples.extend(self.invalid(params, violation).map(|p| (p, false)));
+        }
+        examples
+    }
+
+    /// Checks `check` against the policy engine on `rounds` sets of random fixtures,
+    /// returning the first mismatch, shrunk to a minimal counterexample.
+    pub fn fuzz<F>(&mut self, scheme: Scheme, rounds: usize, check: F) -> Option<Counterexample>
+    where
+        F: Fn(&Params, &str) -> bool,
+    {
+        let template = scheme.template();
+        for _ in 0..rounds {
+            let params = self.params();
+            let policy = template.instantiate(&params);
+            for (password, expected) in self.examples(scheme, &params) {
+                if check(&params, &password) != expected {
+                    let password = shrink(&password, &self.alphabet, |s| {
+                        check(&params, s) != policy.check(s)
+                    });
+                    let expected = policy.check(&password);
+                    return Some(Counterexample {
+                        params,
+                        password,
+                        expected,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    fn letter(&mut self) -> char {
+        self.alphabet[self.rng.gen_range(0..self.alphabet.len())]
+    }
+
+    fn other_letter(&mut self, letter: char) -> char {
+        loop {
+            let c = self.letter();
+            if c != letter {
+                return c;
+            }
+        }
+    }
+
+    /// Returns a password with exactly `count` occurrences of `letter` at random places.
+    fn with_count(&mut self, letter: char, count: usize) -> String {
+        let len = count + self.rng.gen_range(0..=self.max_len);
+        let mut chars = (0..len)
+            .map(|_| self.other_letter(letter))
+            .collect::<Vec<_>>();
+        for i in index::sample(&mut self.rng, len, count) {
+            chars[i] = letter;
+        }
+        chars.into_iter().collect()
+    }
+
+    /// Returns a password holding (or not) the letter at the positions `min` and `max`,
+    /// random letters elsewhere.
+    fn with_positions(&mut self, params: &Params, first: bool, second: bool) -> Option<String> {
+        let &Params { letter, min, max } = params;
+        if min == 0 || max == 0 || (min == max && first != second) {
+            return None;
+        }
+        let len = min.max(max) + self.rng.gen_range(0..=self.max_len);
+        let mut chars = (0..len).map(|_| self.letter()).collect::<Vec<_>>();
+        for &(i, hit) in &[(min, first), (max, second)] {
+            chars[i - 1] = if hit {
+                letter
+            } else {
+                self.other_letter(letter)
+            };
+        }
+        Some(chars.into_iter().collect())
+    }
+}
+
+/// Shrinks the `password` while it is still `failing`: first drops chars,
+/// then replaces them with earlier letters of the `alphabet`.
+pub fn shrink<F>(password: &str, alphabet: &[char], failing: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    let mut chars = password.chars().collect::<Vec<_>>();
+    let mut i = 0;
+    while i < chars.len() {
+        let mut candidate = chars.clone();
+        candidate.remove(i);
+        if failing(&candidate.iter().collect::<String>()) {
+            chars = candidate;
+            // Note: earlier chars may become removable too
+            i = 0;
+        } else {
+            i += 1;
+        }
+    }
+    for i in 0..chars.len() {
+        for &c in alphabet.iter().take_while(|&&c| c != chars[i]) {
+            let mut candidate = chars.clone();
+            candidate[i] = c;
+            if failing(&candidate.iter().collect::<String>()) {
+                chars = candidate;
+                break;
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let mut gen = Generator::seeded(42);
+        for _ in 0..100 {
+            let params = gen.params();
+            for &scheme in &[Scheme::SledRental, Scheme::Toboggan] {
+                let policy = scheme.template().instantiate(&params);
+                for (password, expected) in gen.examples(scheme, &params) {
+                    assert_eq!(
+                        policy.check(&password),
+                        expected,
+                        "{:?} {}",
+                        params,
+                        password
+                    );
+                }
+            }
+        }
+        let params = Params {
+            letter: 'a',
+            min: 2,
+            max: 2,
+        };
+        assert_eq!(gen.valid(Scheme::Toboggan, &params), None);
+        assert!(gen.invalid(&params, Violation::BothPositions).is_some());
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let mut gen = Generator::seeded(2020);
+        assert_eq!(
+            gen.fuzz(Scheme::SledRental, 500, Params::is_valid_first),
+            None
+        );
+        assert_eq!(
+            gen.fuzz(Scheme::Toboggan, 500, Params::is_valid_second),
+            None
+        );
+
+        // Off-by-one in the upper bound is found and shrunk to `max + 1` letters
+        let buggy = |p: &Params, s: &str| s.chars().filter(|&c| c == p.letter).count() >= p.min;
+        let found = gen.fuzz(Scheme::SledRental, 500, buggy).unwrap();
+        assert!(!found.expected);
+        assert_eq!(
+            found.password,
+            found.params.letter.to_string().repeat(found.params.max + 1)
+        );
+    }
+
+    #[test]
+    fn test_shrink() {
+        let alphabet = ('a'..='z').collect::<Vec<_>>();
+        let failing = |s: &str| s.matches('q').count() >= 2 && s.len() >= 3;
+        assert_eq!(shrink("xyqzzqw", &alphabet, failing), "qqa");
+    }
+}
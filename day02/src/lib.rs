@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use log::info;
+use regex::Regex;
+
+use aoc_common::input::parse_lines;
+use aoc_common::{ParseError, Solution};
+
+pub use crate::policy::{Params, Policy, PositionOp, Template};
+
+pub mod generate;
+pub mod policy;
+pub mod report;
+
+/// Policy given on a line.
+#[derive(Debug, Clone)]
+pub enum Rule {
+    /// Classic `<min>-<max> <letter>`, interpreted by the template selected for the run
+    Params(Params),
+    /// Explicit policy spec, e.g. `all(count(a, 1, 3), forbid(xyz))`
+    Policy(Policy),
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Params(params) => write!(f, "{}", params),
+            Rule::Policy(policy) => write!(f, "{}", policy),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Item {
+    pub rule: Rule,
+    pub password: String,
+}
+
+impl FromStr for Item {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^(\d+)-(\d+)\s+(\w):\s+(\w+)$").unwrap();
+            static ref RE_PASSWORD: Regex = Regex::new(r"^:\s+(\w+)$").unwrap();
+        }
+        if let Some(caps) = RE.captures(s) {
+            let number = |i: usize| {
+                let m = caps.get(i).unwrap().as_str();
+                m.parse::<usize>()
+                    .map_err(|e| ParseError::at(s, m, format!("Bad number `{}`: {}", m, e)))
+            };
+            let min = number(1)?;
+            let max = number(2)?;
+            let letter = caps[3].chars().next().unwrap();
+            let password = caps[4].to_string();
+            return Ok(Item {
+                rule: Rule::Params(Params { letter, min, max }),
+                password,
+            });
+        }
+
+        // Note: the password has no colons, so the last one ends the spec
+        let (spec, password) = match s.rfind(':') {
+            Some(i) => (&s[..i], &s[i..]),
+            None => {
+                return Err(ParseError::at(
+                    s,
+                    s,
+                    "Expected `<min>-<max> <letter>: <password>` or `<policy>: <password>`",
+                ))
+            }
+        };
+        let policy = spec
+            .parse::<Policy>()
+            .map_err(|e| ParseError::locate(e, s, spec))?;
+        let caps = RE_PASSWORD
+            .captures(password)
+            .ok_or_else(|| ParseError::at(s, password, "Expected `: <password>`"))?;
+        Ok(Item {
+            rule: Rule::Policy(policy),
+            password: caps[1].to_string(),
+        })
+    }
+}
+
+impl Item {
+    /// Returns the policy of this line: either its explicit one,
+    /// or the `template` instantiated with its parameters.
+    pub fn policy(&self, template: &Template) -> Cow<'_, Policy> {
+        match &self.rule {
+            Rule::Params(params) => Cow::Owned(template.instantiate(params)),
+            Rule::Policy(policy) => Cow::Borrowed(policy),
+        }
+    }
+
+    pub fn is_valid(&self, template: &Template) -> bool {
+        self.policy(template).check(&self.password)
+    }
+}
+
+/// Counts the items valid under their policies, using `template` for the classic lines.
+pub fn count_valid(items: &[Item], template: &Template) -> usize {
+    items.iter().filter(|item| item.is_valid(template)).count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Item>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input)
+    }
+
+    fn part_one(items: &Self::Input) -> Result<Self::AnswerOne> {
+        let valid_first = count_valid(items, &Template::new(Template::SLED_RENTAL)?);
+        info!("Total valid items (first way): {}", valid_first);
+        Ok(valid_first)
+    }
+
+    fn part_two(items: &Self::Input) -> Result<Self::AnswerTwo> {
+        let valid_second = count_valid(items, &Template::new(Template::TOBOGGAN)?);
+        info!("Total valid items (second way): {}", valid_second);
+        Ok(valid_second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let items = Day02::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n").unwrap();
+        assert_eq!(Day02::part_one(&items).unwrap(), 2);
+        assert_eq!(Day02::part_two(&items).unwrap(), 1);
+        // Note: position 0 makes the line invalid rather than the template
+        let items = Day02::parse("0-2 a: abc\n").unwrap();
+        assert_eq!(Day02::part_two(&items).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_policy_per_line() {
+        let input = "1-3 a: abcde\nall(count(c, 2, 9), forbid(xyz)): ccccccccc\nforbid(a): abc\n";
+        let items = Day02::parse(input).unwrap();
+        let template = Template::new("or($letter, $min, $max)").unwrap();
+        assert_eq!(count_valid(&items, &template), 2);
+        let e = Day02::parse("count(a, 1, x): abc")
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!((e.line, e.column), (1, 13));
+    }
+}
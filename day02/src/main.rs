@@ -1,81 +1,60 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::str::FromStr;
+use std::env;
 
-use anyhow::Result;
-use lazy_static::lazy_static;
-use regex::Regex;
+use anyhow::{bail, Result};
+use log::info;
 
-#[derive(Debug)]
-struct Item {
-    policy: Policy,
-    password: String,
-}
-
-#[derive(Debug)]
-struct Policy {
-    letter: char,
-    min: usize,
-    max: usize,
-}
-
-impl FromStr for Item {
-    type Err = ();
+use aoc_common::input::read_input;
+use aoc_common::{logging, with_file, Solution};
+use day02::report::Report;
+use day02::{count_valid, Day02, Template};
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"(\d+)-(\d+)\s+(\w):\s+(\w+)").unwrap();
+fn main() -> Result<()> {
+    logging::init_from_env()?;
+
+    // Usage: day02 [--report | --csv] [<policy template>]
+    let mut report = false;
+    let mut csv = false;
+    let mut spec = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--report" => report = true,
+            "--csv" => csv = true,
+            _ if arg.starts_with("--") => bail!("Unknown option `{}`", arg),
+            _ => spec = Some(arg),
         }
-        let caps = RE.captures(s).unwrap();
-        let min: usize = caps.get(1).unwrap().as_str().parse().unwrap();
-        let max: usize = caps.get(2).unwrap().as_str().parse().unwrap();
-        let letter = caps.get(3).unwrap().as_str().chars().next().unwrap();
-        let password = caps.get(4).unwrap().as_str().to_string();
-        Ok(Item {
-            policy: Policy { letter, min, max },
-            password,
-        })
-    }
-}
-
-impl Item {
-    fn is_valid_first(&self) -> bool {
-        let Policy { letter, min, max } = self.policy;
-        let count = self.password.chars().filter(|&c| c == letter).count();
-        min <= count && count <= max
     }
 
-    fn is_valid_second(&self) -> bool {
-        let Policy {
-            letter,
-            min: i,
-            max: j,
-        } = self.policy;
-        let len = self.password.len();
-        if i > len || j > len {
-            return false;
+    let path = "data/input.txt";
+    let input = read_input(path)?;
+    let data = Day02::parse(&input).map_err(|e| with_file(e, path))?;
+
+    if report || csv {
+        let templates = match &spec {
+            Some(spec) => vec![Template::new(spec)?],
+            None => vec![
+                Template::new(Template::SLED_RENTAL)?,
+                Template::new(Template::TOBOGGAN)?,
+            ],
+        };
+        let report = Report::new(&data, templates);
+        if csv {
+            print!("{}", report.to_csv());
+        } else {
+            print!("{}", report);
         }
-        let a = self.password.chars().nth(i - 1).unwrap();
-        let b = self.password.chars().nth(j - 1).unwrap();
-        (a == letter) ^ (b == letter)
+        return Ok(());
     }
-}
 
-fn main() -> Result<()> {
-    let path = "data/input.txt";
-    let items: Vec<Item> = BufReader::new(File::open(path)?)
-        .lines()
-        .filter_map(|x| x.ok())
-        .map(|line| line.parse().unwrap())
-        .collect();
-
-    let valid_first = items.iter().filter(|&x| Item::is_valid_first(x)).count();
-    println!("Total valid items (first way): {}", valid_first);
-    assert_eq!(620, valid_first);
+    // Note: a policy template, e.g. `and($letter, $min, $max)`, may be given for the run
+    if let Some(spec) = spec {
+        let template = Template::new(&spec)?;
+        info!("Using policy `{}`", template);
+        println!("[valid] {}", count_valid(&data, &template));
+        return Ok(());
+    }
 
-    let valid_second = items.iter().filter(|&x| x.is_valid_second()).count();
-    println!("Total valid items (second way): {}", valid_second);
-    assert_eq!(727, valid_second);
+    println!("[part1] {}", Day02::part_one(&data)?);
+    println!("[part2] {}", Day02::part_two(&data)?);
 
     Ok(())
 }
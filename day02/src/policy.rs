@@ -0,0 +1,789 @@
+//! Declarative password policies.
+//!
+//! Policies are written as specs like `all(count(a, 1, 3), forbid("xyz"))`:
+//!
+//! - `count(a, 1, 3)` - letter `a` occurs from 1 to 3 times,
+//! - `xor(a, 1, 3)` - letter `a` is at exactly one of the (1-based) positions,
+//! - `and(a, 1, 3)` / `or(a, 1, 3)` - letter `a` is at all / any of the positions,
+//! - `forbid("xyz")` - none of the letters occurs,
+//! - `regex("^[a-z]+$")` - the password matches the regex,
+//! - `all(p, q, ...)` / `any(p, q, ...)` / `not(p)` - combinations of policies.
+//!
+//! In a [`Template`], the letter and numbers may be replaced with `$letter`, `$min` and `$max`,
+//! which are taken from the classic `<min>-<max> <letter>` prefix of each line.
+
+use std::fmt;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use aoc_common::ParseError;
+
+/// Parameters of the classic `<min>-<max> <letter>` policy line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Params {
+    pub letter: char,
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Params {
+    /// Checks the sled rental policy: `letter` occurs from `min` to `max` times.
+    pub fn is_valid_first(&self, password: &str) -> bool {
+        let count = password.chars().filter(|&c| c == self.letter).count();
+        self.min <= count && count <= self.max
+    }
+
+    /// Checks the toboggan policy: `letter` is at exactly one of the positions `min` and `max`.
+    pub fn is_valid_second(&self, password: &str) -> bool {
+        let Params {
+            letter,
+            min: i,
+            max: j,
+        } = *self;
+        let len = password.len();
+        if i == 0 || j == 0 || i > len || j > len {
+            return false;
+        }
+        let a = password.chars().nth(i - 1).unwrap();
+        let b = password.chars().nth(j - 1).unwrap();
+        (a == letter) ^ (b == letter)
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}", self.min, self.max, self.letter)
+    }
+}
+
+/// How the letters at several positions are combined.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PositionOp {
+    /// Exactly one position holds the letter
+    Xor,
+    /// All positions hold the letter
+    And,
+    /// At least one position holds the letter
+    Or,
+}
+
+impl PositionOp {
+    fn name(&self) -> &'static str {
+        match self {
+            PositionOp::Xor => "xor",
+            PositionOp::And => "and",
+            PositionOp::Or => "or",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Policy {
+    Count {
+        letter: char,
+        min: usize,
+        max: usize,
+    },
+    Positions {
+        letter: char,
+        /// 1-based positions
+        positions: Vec<usize>,
+        op: PositionOp,
+    },
+    Forbidden(Vec<char>),
+    Matches(Regex),
+    All(Vec<Policy>),
+    Any(Vec<Policy>),
+    Not(Box<Policy>),
+}
+
+impl Policy {
+    pub fn check(&self, password: &str) -> bool {
+        self.verify(password).is_ok()
+    }
+
+    /// Checks the `password`, explaining why it violates the policy.
+    pub fn verify(&self, password: &str) -> Result<(), Failure> {
+        match self {
+            &Policy::Count { letter, min, max } => {
+                let found = password.chars().filter(|&c| c == letter).count();
+                if found < min {
+                    Err(Failure::TooFew {
+                        letter,
+                        found,
+                        min,
+                        max,
+                    })
+                } else if found > max {
+                    Err(Failure::TooMany {
+                        letter,
+                        found,
+                        min,
+                        max,
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            Policy::Positions {
+                letter,
+                positions,
+                op,
+            } => {
+                let chars = password.chars().collect::<Vec<_>>();
+                let matched = positions
+                    .iter()
+                    .copied()
+                    .filter(|&i| i > 0 && chars.get(i - 1) == Some(letter))
+                    .collect::<Vec<_>>();
+                // Note: a template may get the position 0 from a classic line,
+                //  which no password satisfies (as in `Params::is_valid_second`)
+                let valid = !positions.contains(&0)
+                    && match op {
+                        PositionOp::Xor => matched.len() == 1,
+                        PositionOp::And => matched.len() == positions.len(),
+                        PositionOp::Or => !matched.is_empty(),
+                    };
+                if valid {
+                    Ok(())
+                } else {
+                    Err(Failure::Positions {
+                        letter: *letter,
+                        matched,
+                        positions: positions.clone(),
+                        op: *op,
+                    })
+                }
+            }
+            Policy::Forbidden(letters) => match password.chars().position(|c| letters.contains(&c))
+            {
+                Some(i) => Err(Failure::Forbidden {
+                    letter: password.chars().nth(i).unwrap(),
+                    position: i + 1,
+                }),
+                None => Ok(()),
+            },
+            Policy::Matches(re) if re.is_match(password) => Ok(()),
+            Policy::Matches(re) => Err(Failure::NoMatch {
+                regex: re.as_str().to_string(),
+            }),
+            Policy::All(policies) => policies.iter().try_for_each(|p| p.verify(password)),
+            Policy::Any(policies) => {
+                let mut failures = Vec::new();
+                for p in policies {
+                    match p.verify(password) {
+                        Ok(()) => return Ok(()),
+                        Err(failure) => failures.push(failure),
+                    }
+                }
+                Err(Failure::NoneOf(failures))
+            }
+            Policy::Not(policy) => match policy.verify(password) {
+                Ok(()) => Err(Failure::Negated {
+                    policy: policy.to_string(),
+                }),
+                Err(_) => Ok(()),
+            },
+        }
+    }
+}
+
+/// Reason why a password violates a policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    TooFew {
+        letter: char,
+        found: usize,
+        min: usize,
+        max: usize,
+    },
+    TooMany {
+        letter: char,
+        found: usize,
+        min: usize,
+        max: usize,
+    },
+    Positions {
+        letter: char,
+        /// Positions holding the letter
+        matched: Vec<usize>,
+        positions: Vec<usize>,
+        op: PositionOp,
+    },
+    Forbidden {
+        letter: char,
+        position: usize,
+    },
+    NoMatch {
+        regex: String,
+    },
+    /// The password satisfies the negated policy
+    Negated {
+        policy: String,
+    },
+    /// Failures of all alternatives
+    NoneOf(Vec<Failure>),
+}
+
+impl Failure {
+    /// Short name of the failure, for summaries.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Failure::TooFew { .. } => "too few",
+            Failure::TooMany { .. } => "too many",
+            Failure::Positions { .. } => "positions",
+            Failure::Forbidden { .. } => "forbidden",
+            Failure::NoMatch { .. } => "no match",
+            Failure::Negated { .. } => "negated",
+            Failure::NoneOf(_) => "none of",
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::TooFew {
+                letter,
+                found,
+                min,
+                max,
+            }
+            | Failure::TooMany {
+                letter,
+                found,
+                min,
+                max,
+            } => write!(f, "found {} `{}`, allowed {}..={}", found, letter, min, max),
+            Failure::Positions {
+                letter,
+                matched,
+                positions,
+                op,
+            } => {
+                let expected = match op {
+                    PositionOp::Xor => "exactly one",
+                    PositionOp::And => "all",
+                    PositionOp::Or => "at least one",
+                };
+                write!(
+                    f,
+                    "`{}` at positions {:?} of {:?}, expected {}",
+                    letter, matched, positions, expected
+                )
+            }
+            Failure::Forbidden { letter, position } => {
+                write!(f, "forbidden `{}` at position {}", letter, position)
+            }
+            Failure::NoMatch { regex } => write!(f, "does not match `{}`", regex),
+            Failure::Negated { policy } => write!(f, "satisfies `{}`", policy),
+            Failure::NoneOf(failures) => {
+                write!(f, "none of: ")?;
+                for (i, failure) in failures.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", failure)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for Policy {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let node = Parser::new(s, false).parse()?;
+        Ok(node.instantiate(None))
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn list(f: &mut fmt::Formatter<'_>, name: &str, policies: &[Policy]) -> fmt::Result {
+            write!(f, "{}(", name)?;
+            for (i, p) in policies.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", p)?;
+            }
+            write!(f, ")")
+        }
+
+        match self {
+            Policy::Count { letter, min, max } => write!(f, "count({}, {}, {})", letter, min, max),
+            Policy::Positions {
+                letter,
+                positions,
+                op,
+            } => {
+                write!(f, "{}({}", op.name(), letter)?;
+                for i in positions {
+                    write!(f, ", {}", i)?;
+                }
+                write!(f, ")")
+            }
+            Policy::Forbidden(letters) => {
+                write!(f, "forbid({:?})", letters.iter().collect::<String>())
+            }
+            Policy::Matches(re) => write!(f, "regex({:?})", re.as_str()),
+            Policy::All(policies) => list(f, "all", policies),
+            Policy::Any(policies) => list(f, "any", policies),
+            Policy::Not(policy) => write!(f, "not({})", policy),
+        }
+    }
+}
+
+/// Policy spec with placeholders (`$letter`, `$min`, `$max`) for the line parameters.
+///
+/// The spec is parsed once; each line only substitutes its parameters.
+#[derive(Debug, Clone)]
+pub struct Template {
+    spec: String,
+    node: Node,
+}
+
+impl Template {
+    /// The policy from the first part of the puzzle.
+    pub const SLED_RENTAL: &'static str = "count($letter, $min, $max)";
+    /// The policy from the second part of the puzzle.
+    pub const TOBOGGAN: &'static str = "xor($letter, $min, $max)";
+
+    pub fn new(spec: &str) -> Result<Self, ParseError> {
+        let node = Parser::new(spec, true).parse()?;
+        Ok(Template {
+            spec: spec.to_string(),
+            node,
+        })
+    }
+
+    /// Builds the policy for a line with the given `params`.
+    pub fn instantiate(&self, params: &Params) -> Policy {
+        self.node.instantiate(Some(params))
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.spec)
+    }
+}
+
+/// Letter argument of a parsed spec.
+#[derive(Debug, Copy, Clone)]
+enum LetterArg {
+    Value(char),
+    /// `$letter`
+    Letter,
+}
+
+/// Number argument of a parsed spec.
+#[derive(Debug, Copy, Clone)]
+enum NumberArg {
+    Value(usize),
+    /// `$min`
+    Min,
+    /// `$max`
+    Max,
+}
+
+impl LetterArg {
+    fn resolve(self, params: Option<&Params>) -> char {
+        match self {
+            LetterArg::Value(c) => c,
+            LetterArg::Letter => placeholder(params).letter,
+        }
+    }
+}
+
+impl NumberArg {
+    fn resolve(self, params: Option<&Params>) -> usize {
+        match self {
+            NumberArg::Value(n) => n,
+            NumberArg::Min => placeholder(params).min,
+            NumberArg::Max => placeholder(params).max,
+        }
+    }
+}
+
+/// Returns the parameters for a placeholder, which the parser only allows in templates.
+fn placeholder(params: Option<&Params>) -> &Params {
+    params.expect("placeholder outside of a template")
+}
+
+/// Parsed spec: a policy tree whose arguments may still be placeholders.
+#[derive(Debug, Clone)]
+enum Node {
+    Count {
+        letter: LetterArg,
+        min: NumberArg,
+        max: NumberArg,
+    },
+    Positions {
+        letter: LetterArg,
+        positions: Vec<NumberArg>,
+        op: PositionOp,
+    },
+    Forbidden(Vec<char>),
+    Matches(Regex),
+    All(Vec<Node>),
+    Any(Vec<Node>),
+    Not(Box<Node>),
+}
+
+impl Node {
+    /// Substitutes the `params` for the placeholders.
+    fn instantiate(&self, params: Option<&Params>) -> Policy {
+        let all = |nodes: &[Node]| nodes.iter().map(|n| n.instantiate(params)).collect();
+        match self {
+            Node::Count { letter, min, max } => Policy::Count {
+                letter: letter.resolve(params),
+                min: min.resolve(params),
+                max: max.resolve(params),
+            },
+            Node::Positions {
+                letter,
+                positions,
+                op,
+            } => Policy::Positions {
+                letter: letter.resolve(params),
+                positions: positions.iter().map(|p| p.resolve(params)).collect(),
+                op: *op,
+            },
+            Node::Forbidden(letters) => Policy::Forbidden(letters.clone()),
+            // Note: clones of a regex share the compiled program
+            Node::Matches(re) => Policy::Matches(re.clone()),
+            Node::All(nodes) => Policy::All(all(nodes)),
+            Node::Any(nodes) => Policy::Any(all(nodes)),
+            Node::Not(node) => Policy::Not(Box::new(node.instantiate(params))),
+        }
+    }
+}
+
+/// Recursive descent parser for policy specs.
+struct Parser<'a> {
+    spec: &'a str,
+    pos: usize,
+    /// Whether placeholders are allowed (in templates)
+    template: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn new(spec: &'a str, template: bool) -> Self {
+        Parser {
+            spec,
+            pos: 0,
+            template,
+        }
+    }
+
+    fn parse(mut self) -> Result<Node, ParseError> {
+        let policy = self.policy()?;
+        self.skip_whitespace();
+        if !self.rest().is_empty() {
+            return Err(self.error("Unexpected input after the policy"));
+        }
+        Ok(policy)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.spec[self.pos..]
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.spec, self.rest(), message)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected `{}`", c)))
+        }
+    }
+
+    /// Takes the longest prefix of chars satisfying `pred`.
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn policy(&mut self) -> Result<Node, ParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let name = self.take_while(|c| c.is_ascii_alphabetic());
+        self.expect('(')?;
+        let policy = match name {
+            "count" => {
+                let letter = self.letter()?;
+                self.expect(',')?;
+                let min = self.number()?;
+                self.expect(',')?;
+                let max = self.number()?;
+                Node::Count { letter, min, max }
+            }
+            "xor" | "and" | "or" => {
+                let op = match name {
+                    "xor" => PositionOp::Xor,
+                    "and" => PositionOp::And,
+                    _ => PositionOp::Or,
+                };
+                let letter = self.letter()?;
+                let mut positions = Vec::new();
+                while self.eat(',') {
+                    self.skip_whitespace();
+                    let at = self.rest();
+                    let position = self.number()?;
+                    if let NumberArg::Value(0) = position {
+                        return Err(ParseError::at(self.spec, at, "Positions are 1-based"));
+                    }
+                    positions.push(position);
+                }
+                if positions.is_empty() {
+                    return Err(self.error("Expected at least one position"));
+                }
+                Node::Positions {
+                    letter,
+                    positions,
+                    op,
+                }
+            }
+            "forbid" => Node::Forbidden(self.string()?.chars().collect()),
+            "regex" => {
+                self.skip_whitespace();
+                let at = self.rest();
+                let re = self.string()?;
+                let re =
+                    Regex::new(&re).map_err(|e| ParseError::at(self.spec, at, e.to_string()))?;
+                Node::Matches(re)
+            }
+            "all" | "any" => {
+                let mut policies = vec![self.policy()?];
+                while self.eat(',') {
+                    policies.push(self.policy()?);
+                }
+                if name == "all" {
+                    Node::All(policies)
+                } else {
+                    Node::Any(policies)
+                }
+            }
+            "not" => Node::Not(Box::new(self.policy()?)),
+            _ => {
+                return Err(ParseError::at(
+                    self.spec,
+                    &self.spec[start..],
+                    format!("Unknown policy `{}`", name),
+                ))
+            }
+        };
+        self.expect(')')?;
+        Ok(policy)
+    }
+
+    /// Parses a placeholder like `$min`, if there is one.
+    fn param(&mut self) -> Result<Option<&'a str>, ParseError> {
+        if !self.eat('$') {
+            return Ok(None);
+        }
+        let at = &self.spec[self.pos - 1..];
+        let name = self.take_while(|c| c.is_ascii_alphanumeric());
+        if !self.template {
+            return Err(ParseError::at(
+                self.spec,
+                at,
+                format!("Placeholder `${}` is only allowed in templates", name),
+            ));
+        }
+        Ok(Some(name))
+    }
+
+    fn letter(&mut self) -> Result<LetterArg, ParseError> {
+        self.skip_whitespace();
+        let at = self.rest();
+        if let Some(name) = self.param()? {
+            return match name {
+                "letter" => Ok(LetterArg::Letter),
+                _ => Err(ParseError::at(
+                    self.spec,
+                    at,
+                    format!("Expected `$letter`, found `${}`", name),
+                )),
+            };
+        }
+        let mut chars = at.chars();
+        match chars.next() {
+            Some(c) if c.is_alphanumeric() => {
+                self.pos += c.len_utf8();
+                Ok(LetterArg::Value(c))
+            }
+            _ => Err(self.error("Expected a letter")),
+        }
+    }
+
+    fn number(&mut self) -> Result<NumberArg, ParseError> {
+        self.skip_whitespace();
+        let at = self.rest();
+        if let Some(name) = self.param()? {
+            return match name {
+                "min" => Ok(NumberArg::Min),
+                "max" => Ok(NumberArg::Max),
+                _ => Err(ParseError::at(
+                    self.spec,
+                    at,
+                    format!("Expected `$min` or `$max`, found `${}`", name),
+                )),
+            };
+        }
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        digits
+            .parse()
+            .map(NumberArg::Value)
+            .map_err(|_| ParseError::at(self.spec, at, "Expected a number"))
+    }
+
+    /// Parses a quoted string with `\"` and `\\` escapes, or a bare word.
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.skip_whitespace();
+        if !self.eat('"') {
+            let word = self.take_while(|c| !c.is_whitespace() && c != ',' && c != ')');
+            if word.is_empty() {
+                return Err(self.error("Expected a string"));
+            }
+            return Ok(word.to_string());
+        }
+        let mut s = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(s);
+                }
+                '\\' => match chars.next() {
+                    Some((_, c @ '"')) | Some((_, c @ '\\')) => s.push(c),
+                    Some((_, c)) => {
+                        s.push('\\');
+                        s.push(c);
+                    }
+                    None => break,
+                },
+                _ => s.push(c),
+            }
+        }
+        Err(self.error("Unterminated string"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        let policy: Policy = "count(a, 1, 3)".parse().unwrap();
+        assert!(policy.check("abcde"));
+        assert!(!policy.check("cdefg"));
+        assert!(!policy.check("aaaa"));
+    }
+
+    #[test]
+    fn test_positions() {
+        let xor: Policy = "xor(a, 1, 3)".parse().unwrap();
+        let and: Policy = "and(a, 1, 3)".parse().unwrap();
+        let or: Policy = "or(a, 1, 3)".parse().unwrap();
+        assert!(xor.check("abcde") && !and.check("abcde") && or.check("abcde"));
+        assert!(!xor.check("abade") && and.check("abade") && or.check("abade"));
+        assert!(!xor.check("bbbde") && !and.check("bbbde") && !or.check("bbbde"));
+        // Note: positions past the end of the password never hold the letter
+        assert!(!or.check("b"));
+    }
+
+    #[test]
+    fn test_combinators() {
+        let policy: Policy = r#"all(count(a, 1, 3), forbid("xyz"), not(regex("^\d")))"#
+            .parse()
+            .unwrap();
+        assert!(policy.check("abcde"));
+        assert!(!policy.check("abcdx"));
+        assert!(!policy.check("1abcd"));
+        let policy: Policy = "any(forbid(a), xor(b, 2))".parse().unwrap();
+        assert!(policy.check("cde"));
+        assert!(policy.check("abc"));
+        assert!(!policy.check("acb"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let policy: Policy = "count(a, 1, 3)".parse().unwrap();
+        let failure = policy.verify("aaaab").unwrap_err();
+        assert_eq!(failure.kind(), "too many");
+        assert_eq!(failure.to_string(), "found 4 `a`, allowed 1..=3");
+        let policy: Policy = "any(xor(a, 1, 3), forbid(b))".parse().unwrap();
+        assert_eq!(
+            policy.verify("abab").unwrap_err().to_string(),
+            "none of: `a` at positions [1, 3] of [1, 3], expected exactly one; \
+             forbidden `b` at position 2"
+        );
+        assert_eq!(policy.verify("abcb"), Ok(()));
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        let spec =
+            r#"all(count(a, 1, 3), or(b, 1, 2, 5), not(any(forbid("xy"), regex("^\"[a-z]+$"))))"#;
+        let policy: Policy = spec.parse().unwrap();
+        assert_eq!(policy.to_string(), spec);
+    }
+
+    #[test]
+    fn test_template() {
+        let template = Template::new(Template::TOBOGGAN).unwrap();
+        let params = Params {
+            letter: 'b',
+            min: 1,
+            max: 3,
+        };
+        let policy = template.instantiate(&params);
+        assert_eq!(policy.to_string(), "xor(b, 1, 3)");
+        assert!(!policy.check("cdefg"));
+        let params = Params { min: 0, ..params };
+        assert!(!template.instantiate(&params).check("cbb"));
+        assert!(!params.is_valid_second("cbb"));
+        assert!(Template::new("count($letter, $min, $middle)").is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        let e = "count(a, 1 3)".parse::<Policy>().unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (12, "Expected `,`"));
+        let e = "xor(a, 0, 3)".parse::<Policy>().unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (8, "Positions are 1-based"));
+        let e = "count($letter, 1, 3)".parse::<Policy>().unwrap_err();
+        assert_eq!(e.column, 7);
+        let e = "maybe(a)".parse::<Policy>().unwrap_err();
+        assert_eq!(e.message, "Unknown policy `maybe`");
+    }
+}
@@ -0,0 +1,177 @@
+//! Per-line validation report: verdicts under each policy, with the reasons of failures.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::policy::{Failure, Template};
+use crate::Item;
+
+/// Width of the longest bar in the summary histogram.
+const BAR_WIDTH: usize = 40;
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    /// 1-based line number in the input
+    pub number: usize,
+    pub rule: String,
+    pub password: String,
+    /// Verdicts under each template of the report
+    pub verdicts: Vec<Result<(), Failure>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub templates: Vec<Template>,
+    pub lines: Vec<Line>,
+}
+
+impl Report {
+    /// Checks all `items` under each of the `templates` (explicit per-line policies
+    /// get the same verdict under all of them).
+    pub fn new(items: &[Item], templates: Vec<Template>) -> Self {
+        let mut lines = Vec::with_capacity(items.len());
+        for (i, item) in items.iter().enumerate() {
+            let verdicts = templates
+                .iter()
+                .map(|template| item.policy(template).verify(&item.password))
+                .collect();
+            lines.push(Line {
+                number: i + 1,
+                rule: item.rule.to_string(),
+                password: item.password.clone(),
+                verdicts,
+            });
+        }
+        Report { templates, lines }
+    }
+
+    /// Returns the number of lines per outcome (`valid` or the kind of failure)
+    /// under each template: `valid` first, then failures by name.
+    pub fn histogram(&self) -> Vec<Vec<(&'static str, usize)>> {
+        (0..self.templates.len())
+            .map(|t| {
+                let mut valid = 0;
+                let mut failures = BTreeMap::new();
+                for line in &self.lines {
+                    match &line.verdicts[t] {
+                        Ok(()) => valid += 1,
+                        Err(failure) => *failures.entry(failure.kind()).or_insert(0) += 1,
+                    }
+                }
+                std::iter::once(("valid", valid)).chain(failures).collect()
+            })
+            .collect()
+    }
+
+    /// Renders the report as CSV: the line, its rule and password,
+    /// then the verdict and the reason under each template.
+    pub fn to_csv(&self) -> String {
+        let mut header = vec!["line".to_string(), "rule".into(), "password".into()];
+        for template in &self.templates {
+            header.push(template.to_string());
+            header.push(format!("{} reason", template));
+        }
+        let mut csv = csv_record(&header);
+        for line in &self.lines {
+            let mut record = vec![
+                line.number.to_string(),
+                line.rule.clone(),
+                line.password.clone(),
+            ];
+            for verdict in &line.verdicts {
+                match verdict {
+                    Ok(()) => record.extend(vec!["valid".to_string(), String::new()]),
+                    Err(failure) => record.extend(vec!["invalid".to_string(), failure.to_string()]),
+                }
+            }
+            csv.push_str(&csv_record(&record));
+        }
+        csv
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .lines
+            .iter()
+            .map(|l| l.password.len())
+            .max()
+            .unwrap_or(0);
+        for line in &self.lines {
+            write!(f, "{:>5}  {:<w$}", line.number, line.password, w = width)?;
+            for verdict in &line.verdicts {
+                match verdict {
+                    Ok(()) => write!(f, " | ok")?,
+                    Err(failure) => write!(f, " | FAIL ({})", failure)?,
+                }
+            }
+            writeln!(f, "  [{}]", line.rule)?;
+        }
+        for (template, histogram) in self.templates.iter().zip(self.histogram()) {
+            writeln!(f)?;
+            writeln!(f, "Summary for `{}`:", template)?;
+            let max = histogram.iter().map(|&(_, n)| n).max().unwrap_or(0).max(1);
+            for (outcome, n) in histogram {
+                let bar = "#".repeat((n * BAR_WIDTH).div_ceil(max));
+                writeln!(f, "  {:<10} {:>5} {}", outcome, n, bar)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Joins the fields into a CSV record, quoting them where needed.
+fn csv_record(fields: &[String]) -> String {
+    let mut record = fields
+        .iter()
+        .map(|field| {
+            if field.contains(&[',', '"', '\n'][..]) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    record.push('\n');
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day02;
+
+    #[test]
+    fn test_sample_report() {
+        let items = Day02::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n").unwrap();
+        let templates = vec![
+            Template::new(Template::SLED_RENTAL).unwrap(),
+            Template::new(Template::TOBOGGAN).unwrap(),
+        ];
+        let report = Report::new(&items, templates);
+        assert_eq!(
+            report.histogram(),
+            vec![
+                vec![("valid", 2), ("too few", 1)],
+                vec![("valid", 1), ("positions", 2)],
+            ]
+        );
+        let csv = report.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "line,rule,password,\"count($letter, $min, $max)\",\"count($letter, $min, $max) reason\",\
+             \"xor($letter, $min, $max)\",\"xor($letter, $min, $max) reason\""
+        );
+        assert_eq!(lines.next().unwrap(), "1,1-3 a,abcde,valid,,valid,");
+        assert_eq!(
+            lines.next().unwrap(),
+            "2,1-3 b,cdefg,invalid,\"found 0 `b`, allowed 1..=3\",\
+             invalid,\"`b` at positions [] of [1, 3], expected exactly one\""
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use indicatif::ProgressIterator;
+use log::info;
+
+use aoc_common::input::{parse_lines, parse_lines_with};
+use aoc_common::{ParseError, Solution};
+
+pub use crate::render::{render, Style};
+pub use crate::slope::{Ranking, Slope};
+pub use crate::terrain::{Counts, Kind, Legend};
+
+pub mod render;
+pub mod slope;
+pub mod terrain;
+
+#[derive(Debug)]
+pub struct Line {
+    data: Vec<Kind>,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Legend::default().parse_line(s)
+    }
+}
+
+/// Parses the map with the given `legend`.
+pub fn parse_map(input: &str, legend: &Legend) -> Result<Vec<Line>> {
+    parse_lines_with(input, |line| legend.parse_line(line))
+}
+
+/// Slopes `(right, down)` checked in the second part.
+pub const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+// Note:
+//  i -- vertical
+//  j -- horizontal
+
+fn count_trees(lines: &[Line], slope: Slope) -> usize {
+    let steps = lines.len().div_ceil(slope.down);
+    let counter = slope
+        .path(lines)
+        .progress_count(steps as u64)
+        .filter(|&(i, j)| lines[i].data[j] == Kind::Tree)
+        .count();
+    info!("Count trees for slope {}: {}", slope, counter);
+    counter
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Line>;
+    type AnswerOne = usize;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input)
+    }
+
+    fn part_one(lines: &Self::Input) -> Result<Self::AnswerOne> {
+        Ok(count_trees(lines, Slope::new(3, 1)))
+    }
+
+    fn part_two(lines: &Self::Input) -> Result<Self::AnswerTwo> {
+        let product: u64 = SLOPES
+            .iter()
+            .map(|&(right, down)| count_trees(lines, Slope::new(right, down)) as u64)
+            .product();
+        info!("Product: {}", product);
+        Ok(product)
+    }
+}
@@ -1,77 +1,99 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::str::FromStr;
+use std::env;
 
-use anyhow::{anyhow, Error, Result};
-use indicatif::ProgressIterator;
-use itertools::Itertools;
+use anyhow::{bail, Context, Result};
 
-use crate::Location::{Empty, Occupied};
+use aoc_common::input::read_input;
+use aoc_common::{logging, with_file, Solution};
+use day03::{parse_map, render, Day03, Legend, Ranking, Slope, Style, SLOPES};
 
-struct Line {
-    data: Vec<Location>,
-}
+const USAGE: &str =
+    "Usage: day03 [--rank <bound>] [--legend <file>] [--render <right>,<down> [--color]]";
 
-#[derive(PartialEq)]
-enum Location {
-    Empty,
-    Occupied,
-}
+fn main() -> Result<()> {
+    logging::init_from_env()?;
 
-impl Location {
-    fn new(c: char) -> Result<Self> {
-        match c {
-            '.' => Ok(Empty),
-            '#' => Ok(Occupied),
-            _ => Err(anyhow!("Bad location character: `{}`", c)),
+    let mut rank = None;
+    let mut legend_path = None;
+    let mut slope = None;
+    let mut style = Style::Plain;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().context(USAGE);
+        match arg.as_str() {
+            "--rank" => {
+                let bound = value()?;
+                rank = Some(
+                    bound
+                        .parse::<usize>()
+                        .with_context(|| format!("Bad slope bound `{}`", bound))?,
+                );
+            }
+            "--legend" => legend_path = Some(value()?),
+            "--render" => {
+                let spec = value()?;
+                let (right, down) = spec
+                    .split_once(',')
+                    .and_then(|(r, d)| Some((r.trim().parse().ok()?, d.trim().parse().ok()?)))
+                    .filter(|&(_, down)| down > 0)
+                    .with_context(|| format!("Bad slope `{}`, expected `<right>,<down>`", spec))?;
+                slope = Some(Slope::new(right, down));
+            }
+            "--color" => style = Style::Ansi,
+            _ => bail!(USAGE),
         }
     }
-}
 
-impl FromStr for Line {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let data = s.chars().map(|c| Location::new(c)).try_collect()?;
-        Ok(Line { data })
-    }
-}
-
-fn main() -> Result<()> {
     let path = "data/input.txt";
-    let lines: Vec<Line> = BufReader::new(File::open(path)?)
-        .lines()
-        .filter_map(|x| x.ok())
-        .map(|line| line.parse().unwrap())
-        .collect();
+    let input = read_input(path)?;
 
-    // Note:
-    //  i -- vertical
-    //  j -- horizontal
+    let legend = match &legend_path {
+        Some(legend_path) => read_input(legend_path)?
+            .parse::<Legend>()
+            .map_err(|e| with_file(e, legend_path))?,
+        None => Legend::default(),
+    };
+    let data = parse_map(&input, &legend).map_err(|e| with_file(e, path))?;
 
-    let steps = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
-    let product: u64 = steps
-        .iter()
-        .map(|&(step_j, step_i)| {
-            let mut counter = 0;
-            let mut j = 0;
-            for i in (0..lines.len()).step_by(step_i).progress() {
-                let ref line = lines[i].data;
-                let ref loc = line[j % line.len()];
-                if *loc == Occupied {
-                    counter += 1;
-                }
-                j += step_j;
-                // std::thread::sleep(std::time::Duration::from_millis(1))
-            }
+    if let Some(slope) = slope {
+        print!("{}", render(&data, slope, &legend, style));
+        return Ok(());
+    }
+
+    // Note: with a legend, score the slopes by the weights of all visited cells
+    if legend_path.is_some() {
+        for &(right, down) in SLOPES.iter() {
+            let slope = Slope::new(right, down);
+            let counts = slope.counts(&data);
+            let counts = counts
+                .iter()
+                .map(|(kind, n)| format!("{} {}", kind, n))
+                .collect::<Vec<_>>();
             println!(
-                "Count trees for slope ({}, {}): {}",
-                step_j, step_i, counter
+                "[slope {}] cost {} ({})",
+                slope,
+                slope.cost(&data, &legend),
+                counts.join(", ")
             );
-            counter as u64
-        })
-        .product();
-    println!("Product: {}", product);
+        }
+        return Ok(());
+    }
+
+    if let Some(bound) = rank {
+        let ranking = Ranking::new(&data, bound);
+        for (slope, trees) in &ranking.slopes {
+            println!("{} {}", slope, trees);
+        }
+        for (slope, trees) in ranking.fewest() {
+            println!("[fewest] {} {}", slope, trees);
+        }
+        for (slope, trees) in ranking.most() {
+            println!("[most] {} {}", slope, trees);
+        }
+        return Ok(());
+    }
+
+    println!("[part1] {}", Day03::part_one(&data)?);
+    println!("[part2] {}", Day03::part_two(&data)?);
 
     Ok(())
 }
@@ -0,0 +1,89 @@
+//! Rendering of the horizontally repeated map with a trajectory overlaid.
+
+use crate::{Kind, Legend, Line, Slope};
+
+const ANSI_HIT: &str = "\x1b[1;31m";
+const ANSI_MISS: &str = "\x1b[1;32m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    /// Hits in red, misses in green
+    Ansi,
+}
+
+/// Draws the map, repeated to the right as far as the trajectory goes,
+/// marking visited cells with `O` for a hit tree and `X` for anything else.
+///
+/// Cells are drawn with the symbols of the `legend`, the marks are placed by
+/// the wrapped positions of [`Slope::path`], so a wrong wrap shows up as a broken line.
+pub fn render(lines: &[Line], slope: Slope, legend: &Legend, style: Style) -> String {
+    let width = lines.iter().map(|line| line.data.len()).max().unwrap_or(0);
+    let steps = lines.len().div_ceil(slope.down);
+    let reach = steps.saturating_sub(1) * slope.right + 1;
+    let tiles = reach.div_ceil(width.max(1));
+
+    let mut marks = vec![None; lines.len()];
+    for (k, (i, j)) in slope.path(lines).enumerate() {
+        let len = lines[i].data.len();
+        let tile = k * slope.right / len;
+        marks[i] = Some((tile * len + j, lines[i].data[j] == Kind::Tree));
+    }
+
+    let mut out = String::new();
+    for (line, mark) in lines.iter().zip(marks) {
+        let cells = line.data.iter().cycle().take(tiles * line.data.len());
+        for (col, &kind) in cells.enumerate() {
+            match mark {
+                Some((at, hit)) if at == col => {
+                    let (c, color) = if hit {
+                        ('O', ANSI_HIT)
+                    } else {
+                        ('X', ANSI_MISS)
+                    };
+                    match style {
+                        Style::Plain => out.push(c),
+                        Style::Ansi => {
+                            out.push_str(color);
+                            out.push(c);
+                            out.push_str(ANSI_RESET);
+                        }
+                    }
+                }
+                _ => out.push(legend.symbol(kind).unwrap_or('?')),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day03;
+
+    #[test]
+    fn test_render_sample() {
+        let input = fs::read_to_string("data/sample.txt").unwrap();
+        let lines = Day03::parse(&input).unwrap();
+        let legend = Legend::default();
+        let map = render(&lines, Slope::new(3, 1), &legend, Style::Plain);
+        let rows = map.lines().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 11);
+        assert!(rows.iter().all(|row| row.len() == 33));
+        assert_eq!(rows[0], "X.##.........##.........##.......");
+        assert_eq!(rows[1], "#..X#...#..#...#...#..#...#...#..");
+        assert_eq!(rows[4], ".#...##..#..O...##..#..#...##..#.");
+        assert_eq!(map.matches('O').count(), 7);
+        assert_eq!(map.matches('X').count(), 4);
+
+        let colored = render(&lines, Slope::new(3, 1), &legend, Style::Ansi);
+        assert_eq!(colored.matches(ANSI_HIT).count(), 7);
+    }
+}
@@ -0,0 +1,165 @@
+//! Slopes of the toboggan trajectories and their ranking by trees hit.
+
+use std::fmt;
+
+use crate::{Counts, Kind, Legend, Line};
+
+/// Slope of a trajectory: `right` steps horizontally per `down` steps vertically.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+impl Slope {
+    pub fn new(right: usize, down: usize) -> Self {
+        assert!(down > 0, "Slope must go down");
+        Slope { right, down }
+    }
+
+    /// Returns the positions `(i, j)` visited on the map, with `j` wrapped around the line.
+    pub fn path(self, lines: &[Line]) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..lines.len())
+            .step_by(self.down)
+            .enumerate()
+            .map(move |(k, i)| (i, (k * self.right) % lines[i].data.len()))
+    }
+
+    /// Returns the number of trees hit on the way down.
+    pub fn trees(self, lines: &[Line]) -> usize {
+        self.path(lines)
+            .filter(|&(i, j)| lines[i].data[j] == Kind::Tree)
+            .count()
+    }
+
+    /// Returns the number of visited cells of each kind.
+    pub fn counts(self, lines: &[Line]) -> Counts {
+        let mut counts = Counts::new();
+        for (i, j) in self.path(lines) {
+            *counts.entry(lines[i].data[j]).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns the total weight of the visited cells.
+    pub fn cost(self, lines: &[Line], legend: &Legend) -> i64 {
+        legend.cost(&self.counts(lines))
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.right, self.down)
+    }
+}
+
+/// Returns all distinct rational slopes `right/down` with both parts up to `bound`,
+/// in lowest terms (e.g. `(2, 2)` is the same trajectory as `(1, 1)`, only sparser).
+pub fn rational_slopes(bound: usize) -> Vec<Slope> {
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    let mut slopes = Vec::new();
+    for down in 1..=bound {
+        for right in 0..=bound {
+            if gcd(right, down) == 1 {
+                slopes.push(Slope::new(right, down));
+            }
+        }
+    }
+    slopes
+}
+
+/// Slopes ranked by the number of trees hit, from the fewest to the most.
+#[derive(Debug, Clone)]
+pub struct Ranking {
+    pub slopes: Vec<(Slope, usize)>,
+}
+
+impl Ranking {
+    /// Evaluates all rational slopes with both parts up to `bound`.
+    pub fn new(lines: &[Line], bound: usize) -> Self {
+        let mut slopes = rational_slopes(bound)
+            .into_iter()
+            .map(|slope| (slope, slope.trees(lines)))
+            .collect::<Vec<_>>();
+        slopes.sort_by_key(|&(slope, trees)| (trees, slope));
+        Ranking { slopes }
+    }
+
+    /// Returns the slopes hitting the fewest trees.
+    pub fn fewest(&self) -> &[(Slope, usize)] {
+        let n = self
+            .slopes
+            .iter()
+            .take_while(|&&(_, trees)| trees == self.slopes[0].1)
+            .count();
+        &self.slopes[..n]
+    }
+
+    /// Returns the slopes hitting the most trees.
+    pub fn most(&self) -> &[(Slope, usize)] {
+        let last = self.slopes.len().saturating_sub(1);
+        let n = self
+            .slopes
+            .iter()
+            .rev()
+            .take_while(|&&(_, trees)| trees == self.slopes[last].1)
+            .count();
+        &self.slopes[self.slopes.len() - n..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day03;
+
+    #[test]
+    fn test_sample_ranking() {
+        let input = fs::read_to_string("data/sample.txt").unwrap();
+        let lines = Day03::parse(&input).unwrap();
+        let trees = |right, down| Slope::new(right, down).trees(&lines);
+        assert_eq!(
+            [
+                trees(1, 1),
+                trees(3, 1),
+                trees(5, 1),
+                trees(7, 1),
+                trees(1, 2)
+            ],
+            [2, 7, 3, 4, 2]
+        );
+
+        let ranking = Ranking::new(&lines, 3);
+        assert_eq!(ranking.slopes.len(), rational_slopes(3).len());
+        assert_eq!(ranking.most(), &[(Slope::new(3, 1), 7)]);
+        assert!(ranking
+            .fewest()
+            .iter()
+            .all(|&(_, n)| n == ranking.slopes[0].1));
+        assert!(ranking.fewest().len() < ranking.slopes.len());
+    }
+
+    #[test]
+    fn test_rational_slopes() {
+        let slopes = rational_slopes(2);
+        let expected = [(0, 1), (1, 1), (2, 1), (1, 2)];
+        assert_eq!(
+            slopes,
+            expected
+                .iter()
+                .map(|&(right, down)| Slope::new(right, down))
+                .collect::<Vec<_>>()
+        );
+    }
+}
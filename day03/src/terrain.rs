@@ -0,0 +1,237 @@
+//! Terrain model: kinds of map cells, their symbols and weights.
+//!
+//! A legend is given by lines `<symbol> <kind> [<weight>]`, e.g.:
+//!
+//! ```text
+//! . open 0
+//! # tree 1
+//! ^ rock 5
+//! * snow 2
+//! = lift -3
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+
+use aoc_common::ParseError;
+
+use crate::Line;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Kind {
+    Open,
+    Tree,
+    Rock,
+    Snow,
+    Lift,
+}
+
+impl FromStr for Kind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "open" => Ok(Kind::Open),
+            "tree" => Ok(Kind::Tree),
+            "rock" => Ok(Kind::Rock),
+            "snow" => Ok(Kind::Snow),
+            "lift" => Ok(Kind::Lift),
+            _ => Err(anyhow!(
+                "Bad cell kind `{}`, expected `open`, `tree`, `rock`, `snow` or `lift`",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Kind::Open => "open",
+            Kind::Tree => "tree",
+            Kind::Rock => "rock",
+            Kind::Snow => "snow",
+            Kind::Lift => "lift",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Number of visited cells of each kind.
+pub type Counts = BTreeMap<Kind, usize>;
+
+/// Symbols of the map cells and weights of their kinds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Legend {
+    symbols: BTreeMap<char, Kind>,
+    weights: BTreeMap<Kind, i64>,
+}
+
+impl Legend {
+    pub fn kind(&self, symbol: char) -> Option<Kind> {
+        self.symbols.get(&symbol).copied()
+    }
+
+    /// Returns the symbol of the cells of the given `kind`, if there is one.
+    pub fn symbol(&self, kind: Kind) -> Option<char> {
+        self.symbols
+            .iter()
+            .find(|&(_, &k)| k == kind)
+            .map(|(&c, _)| c)
+    }
+
+    /// Returns the weight of the cells of the given `kind` (zero if not weighted).
+    pub fn weight(&self, kind: Kind) -> i64 {
+        self.weights.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Parses a map line, pointing errors at unknown symbols.
+    pub fn parse_line(&self, s: &str) -> Result<Line, ParseError> {
+        // Note: the path wraps around each line, which is impossible on an empty one
+        if s.is_empty() {
+            return Err(ParseError::at(s, s, "Empty map line"));
+        }
+        let data = s
+            .char_indices()
+            .map(|(i, c)| {
+                self.kind(c).ok_or_else(|| {
+                    let known = self.symbols.keys().map(|c| format!("`{}`", c));
+                    let known = known.collect::<Vec<_>>().join(", ");
+                    ParseError::at(
+                        s,
+                        &s[i..],
+                        format!("Unknown cell `{}`, expected {}", c, known),
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Line { data })
+    }
+
+    /// Returns the total weight of the `counts`.
+    pub fn cost(&self, counts: &Counts) -> i64 {
+        counts
+            .iter()
+            .map(|(&kind, &n)| self.weight(kind) * n as i64)
+            .sum()
+    }
+
+    fn add_entry(&mut self, line: &str) -> Result<(), ParseError> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let (symbol, kind, weight) = match words.as_slice() {
+            [symbol, kind] => (*symbol, *kind, None),
+            [symbol, kind, weight] => (*symbol, *kind, Some(*weight)),
+            _ => {
+                return Err(ParseError::at(
+                    line,
+                    line,
+                    "Expected `<symbol> <kind> [<weight>]`",
+                ))
+            }
+        };
+        let mut chars = symbol.chars();
+        let c = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return Err(ParseError::at(line, symbol, "Symbol must be a single char")),
+        };
+        if self.symbols.contains_key(&c) {
+            return Err(ParseError::at(
+                line,
+                symbol,
+                format!("Duplicate symbol `{}`", c),
+            ));
+        }
+        let kind = kind
+            .parse::<Kind>()
+            .map_err(|e| ParseError::locate(e, line, kind))?;
+        if let Some(weight) = weight {
+            let w = weight
+                .parse::<i64>()
+                .map_err(|e| ParseError::locate(e, line, weight))?;
+            match self.weights.insert(kind, w) {
+                Some(old) if old != w => {
+                    return Err(ParseError::at(
+                        line,
+                        weight,
+                        format!("Conflicting weights {} and {} for `{}`", old, w, kind),
+                    ))
+                }
+                _ => {}
+            }
+        }
+        self.symbols.insert(c, kind);
+        Ok(())
+    }
+}
+
+/// The classic legend: `.` for open ground, `#` for trees, each tree weighs 1.
+impl Default for Legend {
+    fn default() -> Self {
+        let mut legend = Legend {
+            symbols: BTreeMap::new(),
+            weights: BTreeMap::new(),
+        };
+        legend.symbols.insert('.', Kind::Open);
+        legend.symbols.insert('#', Kind::Tree);
+        legend.weights.insert(Kind::Tree, 1);
+        legend
+    }
+}
+
+impl FromStr for Legend {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut legend = Legend {
+            symbols: BTreeMap::new(),
+            weights: BTreeMap::new(),
+        };
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            legend
+                .add_entry(line)
+                .map_err(|e| ParseError::locate(e, s, line))?;
+        }
+        Ok(legend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_map, Slope};
+
+    const LEGEND: &str = ". open\n# tree 1\n^ rock 5\n* snow 2\n= lift -3\n";
+
+    #[test]
+    fn test_cost() {
+        let legend: Legend = LEGEND.parse().unwrap();
+        let lines = ["^.#", "*=.", "#.^", "=.."]
+            .iter()
+            .map(|s| legend.parse_line(s).unwrap())
+            .collect::<Vec<_>>();
+        let counts = Slope::new(1, 1).counts(&lines);
+        // Path: `^`, `=`, `^`, `=` (wrapped around)
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            vec![(Kind::Rock, 2), (Kind::Lift, 2)]
+        );
+        assert_eq!(Slope::new(1, 1).cost(&lines, &legend), 4);
+        assert_eq!(Slope::new(0, 1).cost(&lines, &legend), 5 + 2 + 1 - 3);
+    }
+
+    #[test]
+    fn test_errors() {
+        let e = "x tree 1\ny tree 2\n".parse::<Legend>().unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 8));
+        let e = Legend::default().parse_line("..#x").unwrap_err();
+        assert_eq!(e.column, 4);
+        assert_eq!(e.message, "Unknown cell `x`, expected `#`, `.`");
+        let e = parse_map("..#\n\n#..\n", &Legend::default()).unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.message.as_str()), (2, "Empty map line"));
+    }
+}
@@ -0,0 +1,224 @@
+//! Export of passports with their verdicts to JSON and CSV, and import back.
+//!
+//! In JSON, each passport is a record
+//! `{"passport": {"byr": "1980", ...}, "valid": true, "errors": [], "warnings": []}`,
+//! its fields in the order of the input.
+//! In CSV, each field is a column (empty if missing), followed by the `valid`,
+//! `errors` and `warnings` columns; repeated fields keep only the first value.
+//!
+//! On import the verdicts are ignored: the passports are to be validated again.
+
+use std::fmt;
+
+use anyhow::{ensure, Context, Result};
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::schema::Schema;
+use crate::Passport;
+
+/// Columns of the CSV export holding the verdicts, not the fields.
+const VERDICT_COLUMNS: [&str; 3] = ["valid", "errors", "warnings"];
+
+/// Passport with the verdict of the schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub passport: Passport,
+    pub valid: bool,
+    #[serde(default)]
+    pub errors: Vec<String>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+impl Record {
+    pub fn new(passport: &Passport, schema: &Schema) -> Self {
+        let errors = schema.violations(passport);
+        Record {
+            passport: passport.clone(),
+            valid: errors.is_empty(),
+            errors: errors.iter().map(|e| e.to_string()).collect(),
+            warnings: schema
+                .warnings(passport)
+                .iter()
+                .map(|w| w.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Renders the passports with their verdicts as a JSON array of [`Record`]s.
+pub fn to_json(passports: &[Passport], schema: &Schema) -> Result<String> {
+    let records = passports
+        .iter()
+        .map(|passport| Record::new(passport, schema))
+        .collect::<Vec<_>>();
+    Ok(serde_json::to_string_pretty(&records)?)
+}
+
+pub fn from_json(s: &str) -> Result<Vec<Passport>> {
+    let records: Vec<Record> = serde_json::from_str(s).context("Bad passport records")?;
+    Ok(records.into_iter().map(|r| r.passport).collect())
+}
+
+/// Renders the passports with their verdicts as CSV: the fields of the schema first,
+/// then any other fields in the order of appearance, then the verdicts.
+///
+/// Fails if a passport has a field named like a verdict column.
+pub fn to_csv(passports: &[Passport], schema: &Schema) -> Result<String> {
+    let mut columns = schema
+        .fields
+        .iter()
+        .map(|rule| rule.name.as_str())
+        .collect::<Vec<_>>();
+    for passport in passports {
+        for (key, _) in passport.fields() {
+            ensure!(
+                !VERDICT_COLUMNS.contains(&key),
+                "Field `{}` clashes with the verdict column of the same name",
+                key
+            );
+            if !columns.contains(&key) {
+                columns.push(key);
+            }
+        }
+    }
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(columns.iter().chain(VERDICT_COLUMNS.iter()))?;
+    for passport in passports {
+        let record = Record::new(passport, schema);
+        let mut row = columns
+            .iter()
+            .map(|&column| passport.get(column).unwrap_or_default().to_string())
+            .collect::<Vec<_>>();
+        row.push(record.valid.to_string());
+        row.push(record.errors.join("; "));
+        row.push(record.warnings.join("; "));
+        writer.write_record(&row)?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+pub fn from_csv(s: &str) -> Result<Vec<Passport>> {
+    let mut reader = csv::Reader::from_reader(s.as_bytes());
+    let header = reader.headers()?.clone();
+    for column in VERDICT_COLUMNS.iter() {
+        ensure!(
+            header.iter().filter(|c| c == column).count() <= 1,
+            "Column `{}` is repeated, a field clashes with the verdict column",
+            column
+        );
+    }
+    let mut passports = Vec::new();
+    for row in reader.records() {
+        let row = row.context("Bad passport row")?;
+        let passport =
+            Passport::from_fields(header.iter().zip(row.iter()).filter(|&(column, value)| {
+                !VERDICT_COLUMNS.contains(&column) && !value.is_empty()
+            }));
+        passports.push(passport);
+    }
+    Ok(passports)
+}
+
+impl Serialize for Passport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields().count()))?;
+        for (key, value) in self.fields() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Passport {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct FieldsVisitor;
+
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = Passport;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a map of passport fields")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<Passport, A::Error> {
+                let mut passport = Passport::default();
+                while let Some((key, value)) = map.next_entry::<String, String>()? {
+                    passport.insert(&key, &value);
+                }
+                Ok(passport)
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day04;
+
+    const INPUT: &str = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929 tag:\"x,y\"
+";
+
+    #[test]
+    fn test_json_roundtrip() {
+        let passports = Day04::parse(INPUT).unwrap();
+        let json = to_json(&passports, &Schema::passport()).unwrap();
+        let records: Vec<Record> = serde_json::from_str(&json).unwrap();
+        assert!(records[0].valid);
+        assert_eq!(records[1].errors, vec!["`hgt` is missing"]);
+        assert_eq!(records[1].warnings, vec!["unknown field `tag`"]);
+        assert_eq!(from_json(&json).unwrap(), passports);
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let passports = Day04::parse(INPUT).unwrap();
+        let csv = to_csv(&passports, &Schema::passport()).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "byr,iyr,eyr,hgt,hcl,ecl,pid,cid,tag,valid,errors,warnings"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "1937,2017,2020,183cm,#fffffd,gry,860033327,147,,true,,"
+        );
+        // Note: fields come back in the order of the columns
+        let imported = from_csv(&csv).unwrap();
+        assert_eq!(imported.len(), 2);
+        for (a, b) in imported.iter().zip(&passports) {
+            let mut a = a.fields().collect::<Vec<_>>();
+            let mut b = b.fields().collect::<Vec<_>>();
+            a.sort_unstable();
+            b.sort_unstable();
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_csv_verdict_clash() {
+        let passports = Day04::parse("byr:1937 valid:yes\n").unwrap();
+        let e = to_csv(&passports, &Schema::passport()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Field `valid` clashes with the verdict column of the same name"
+        );
+        assert!(from_csv("byr,valid,valid,errors,warnings\n1937,yes,false,,\n").is_err());
+    }
+}
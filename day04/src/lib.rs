@@ -0,0 +1,159 @@
+use anyhow::Result;
+use log::{info, warn};
+
+use aoc_common::{ParseError, Solution};
+
+pub use crate::schema::{
+    FieldRule, Schema, ValidationError, ValidationResult, Warning, NORTH_POLE_CREDENTIALS,
+    PASSPORT_FIELDS,
+};
+pub use crate::stats::BatchStats;
+pub use crate::stream::PassportReader;
+
+pub mod export;
+pub mod schema;
+pub mod stats;
+pub mod stream;
+
+/// Passport as a record of `key:value` fields, in the order of the input.
+///
+/// Any keys are accepted, the [`Schema`] decides which of them are known.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Passport {
+    fields: Vec<(String, String)>,
+}
+
+impl Passport {
+    pub fn from_fields<I, K, V>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Passport {
+            fields: fields
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+
+    /// Returns the value of the field `key` (the first one, if repeated), if it is present.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        self.fields.push((key.to_string(), value.to_string()));
+    }
+
+    /// Returns all fields in the order of the input.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Adds the `key:value` fields from the `line`.
+    fn add_line(&mut self, line: &str) -> Result<(), ParseError> {
+        for item in line.split_whitespace() {
+            let (key, value) = item.split_once(':').ok_or_else(|| {
+                ParseError::at(line, item, format!("Bad key-value pair `{}`", item))
+            })?;
+            self.insert(key, value);
+        }
+        Ok(())
+    }
+}
+
+fn parse_passports(input: &str) -> Result<Vec<Passport>> {
+    PassportReader::new(input.as_bytes()).collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let passports = parse_passports(input)?;
+        let schema = Schema::passport();
+        for (i, passport) in passports.iter().enumerate() {
+            for warning in schema.warnings(passport) {
+                warn!("Passport {}: {}", i + 1, warning);
+            }
+        }
+        Ok(passports)
+    }
+
+    fn part_one(passports: &Self::Input) -> Result<Self::AnswerOne> {
+        // Simple validation: only the presence of the required fields
+        let schema = Schema::passport();
+        let valid_simple = passports.iter().filter(|&x| schema.has_required(x)).count();
+        info!(
+            "Number of valid passports (simple validation): {} of {}",
+            valid_simple,
+            passports.len()
+        );
+        Ok(valid_simple)
+    }
+
+    fn part_two(passports: &Self::Input) -> Result<Self::AnswerTwo> {
+        // Complex validation
+        let schema = Schema::passport();
+        let valid_complex = passports.iter().filter(|&x| schema.is_valid(x)).count();
+        info!(
+            "Number of valid passports (complex validation): {} of {}",
+            valid_complex,
+            passports.len()
+        );
+        Ok(valid_complex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_input_first_and_last() {
+        let input = fs::read_to_string("data/input.txt").unwrap();
+        let passports = Day04::parse(&input).unwrap();
+
+        // First passport (invalid)
+        assert_eq!(
+            Some(&Passport::from_fields(vec![
+                ("iyr", "2013"),
+                ("hcl", "#ceb3a1"),
+                ("hgt", "151cm"),
+                ("eyr", "2030"),
+                ("byr", "1943"),
+                ("ecl", "grn"),
+            ])),
+            passports.first()
+        );
+        assert_eq!(passports.first().unwrap().get("pid"), None);
+        let schema = Schema::passport();
+        assert!(!schema.has_required(passports.first().unwrap()));
+        // Last passport (valid)
+        assert_eq!(
+            Some(&Passport::from_fields(vec![
+                ("byr", "2001"),
+                ("hcl", "#4784a2"),
+                ("hgt", "161cm"),
+                ("iyr", "2014"),
+                ("eyr", "2025"),
+                ("pid", "955262336"),
+                ("ecl", "amb"),
+            ])),
+            passports.last()
+        );
+        assert_eq!(passports.last().unwrap().get("cid"), None);
+        assert!(schema.has_required(passports.last().unwrap()));
+    }
+}
@@ -1,266 +1,118 @@
-use std::convert::TryInto;
+use std::env;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::str::FromStr;
-use std::{error, fmt};
+use std::io::{self, BufRead, BufReader};
 
-use anyhow::{anyhow, Error, Result};
-use once_cell_regex::regex;
-use regex::Regex;
+use anyhow::{bail, Context, Result};
+use log::info;
 
-use crate::ValidationError::*;
-use crate::ValidationResult::*;
+use aoc_common::input::read_input;
+use aoc_common::{logging, with_file, Solution};
+use day04::{export, stream, BatchStats, Day04, PassportReader, Schema};
 
-#[derive(Debug, Default, PartialEq)]
-struct Passport {
-    /// Birth Year
-    byr: Option<String>,
-    /// Issue Year
-    iyr: Option<String>,
-    /// Expiration Year
-    eyr: Option<String>,
-    /// Height
-    hgt: Option<String>,
-    /// Hair Color
-    hcl: Option<String>,
-    /// Eye Color
-    ecl: Option<String>,
-    /// Passport ID
-    pid: Option<String>,
-    /// Country ID (optional for validation)
-    cid: Option<String>,
-}
-
-#[derive(Debug, PartialEq)]
-enum ValidationResult {
-    Valid,
-    Invalid(ValidationError),
-}
-
-#[derive(Debug, PartialEq)]
-enum ValidationError {
-    FieldIsNone(&'static str),
-    FieldDoesNotMatchRegex(&'static str),
-    FieldIsNotInRange(&'static str),
-    HeightCmIsNotInRange,
-    HeightInIsNotInRange,
-}
-
-impl fmt::Display for ValidationError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
-    }
-}
-
-impl error::Error for ValidationError {}
+const USAGE: &str = "Usage: day04 [--input <file>] [--schema <file>] [--require <field>,...] \
+                     [--errors | --export json|csv | --stream]";
 
-impl Passport {
-    fn is_valid_simple(&self) -> bool {
-        self.byr.is_some()
-            && self.iyr.is_some()
-            && self.eyr.is_some()
-            && self.hgt.is_some()
-            && self.hcl.is_some()
-            && self.ecl.is_some()
-            && self.pid.is_some()
-        // Note: self.cid is optional for validation
-    }
-
-    fn validate_complex(&self) -> ValidationResult {
-        // byr (Birth Year) - four digits; at least 1920 and at most 2002.
-        if let Some(byr) = &self.byr {
-            if let Some(caps) = regex!(r"^(\d{4})$").captures(&byr) {
-                let n = caps.get(1).unwrap().as_str().parse::<i32>().unwrap();
-                if !(1920..=2002).contains(&n) {
-                    return Invalid(FieldIsNotInRange("byr"));
-                }
-            } else {
-                return Invalid(FieldDoesNotMatchRegex("byr"));
-            }
-        } else {
-            return Invalid(FieldIsNone("byr"));
-        }
-
-        // iyr (Issue Year) - four digits; at least 2010 and at most 2020.
-        if let Some(iyr) = &self.iyr {
-            if let Some(caps) = regex!(r"^(\d{4})$").captures(&iyr) {
-                let n = caps.get(1).unwrap().as_str().parse::<i32>().unwrap();
-                if !(2010..=2020).contains(&n) {
-                    return Invalid(FieldIsNotInRange("iyr"));
-                }
-            } else {
-                return Invalid(FieldDoesNotMatchRegex("iyr"));
-            }
-        } else {
-            return Invalid(FieldIsNone("iyr"));
+fn main() -> Result<()> {
+    logging::init_from_env()?;
+
+    let mut path = "data/input.txt".to_string();
+    let mut schema_path = None;
+    let mut require = None;
+    let mut errors = false;
+    let mut export_format = None;
+    let mut streaming = false;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--schema" => schema_path = Some(args.next().context(USAGE)?),
+            "--require" => require = Some(args.next().context(USAGE)?),
+            "--input" => path = args.next().context(USAGE)?,
+            "--errors" => errors = true,
+            "--stream" => streaming = true,
+            "--export" => export_format = Some(args.next().context(USAGE)?),
+            _ => bail!(USAGE),
         }
+    }
 
-        // eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
-        if let Some(eyr) = &self.eyr {
-            if let Some(caps) = regex!(r"^(\d{4})$").captures(&eyr) {
-                let n = caps.get(1).unwrap().as_str().parse::<i32>().unwrap();
-                if !(2020..=2030).contains(&n) {
-                    return Invalid(FieldIsNotInRange("eyr"));
-                }
-            } else {
-                return Invalid(FieldDoesNotMatchRegex("eyr"));
-            }
-        } else {
-            return Invalid(FieldIsNone("eyr"));
+    let mut schema = match &schema_path {
+        Some(schema_path) => {
+            info!("Using schema from `{}`", schema_path);
+            read_input(schema_path)?
+                .parse::<Schema>()
+                .map_err(|e| with_file(e, schema_path))?
         }
+        None => Schema::passport(),
+    };
+    if let Some(fields) = &require {
+        schema = schema.require(&fields.split(',').collect::<Vec<_>>());
+    }
 
-        // hgt (Height) - a number followed by either cm or in:
-        //  If cm, the number must be at least 150 and at most 193.
-        //  If in, the number must be at least 59 and at most 76
-        if let Some(hgt) = &self.hgt {
-            if let Some(caps) = regex!(r"^(\d+)(cm|in)$").captures(&hgt) {
-                let n = caps.get(1).unwrap().as_str().parse::<i32>().unwrap();
-                let unit = caps.get(2).unwrap().as_str();
-                match unit {
-                    "cm" => {
-                        if !(150..=193).contains(&n) {
-                            return Invalid(HeightCmIsNotInRange);
-                        }
-                    }
-                    "in" => {
-                        if !(59..=76).contains(&n) {
-                            return Invalid(HeightInIsNotInRange);
-                        }
-                    }
-                    _ => {}
-                }
-            } else {
-                return Invalid(FieldDoesNotMatchRegex("hgt"));
-            }
+    // Note: validate the passports one by one, without reading the whole input
+    if streaming {
+        let reader: Box<dyn BufRead> = if path == "-" {
+            Box::new(io::stdin().lock())
         } else {
-            return Invalid(FieldIsNone("hgt"));
-        }
+            let file =
+                File::open(&path).with_context(|| format!("Could not read input `{}`", path))?;
+            Box::new(BufReader::new(file))
+        };
+        let stats = stream::validate(PassportReader::new(reader), &schema)
+            .map_err(|e| with_file(e, &path))?;
+        print!("{}", stats);
+        println!("[valid] {}", stats.valid);
+        return Ok(());
+    }
 
-        // hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
-        if let Some(hcl) = &self.hcl {
-            if !regex!(r"^#[0-9a-f]{6}$").is_match(&hcl) {
-                return Invalid(FieldDoesNotMatchRegex("hcl"));
-            }
-        } else {
-            return Invalid(FieldIsNone("hcl"));
-        }
+    // Note: exported batches are imported back by their extension
+    let input = read_input(&path)?;
+    let data = if path.ends_with(".json") {
+        export::from_json(&input)
+    } else if path.ends_with(".csv") {
+        export::from_csv(&input)
+    } else {
+        Day04::parse(&input)
+    }
+    .map_err(|e| with_file(e, &path))?;
 
-        // ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
-        if let Some(ecl) = &self.ecl {
-            if !regex!(r"^(amb|blu|brn|gry|grn|hzl|oth)$").is_match(&ecl) {
-                return Invalid(FieldDoesNotMatchRegex("ecl"));
-            }
-        } else {
-            return Invalid(FieldIsNone("ecl"));
+    match export_format.as_deref() {
+        Some("json") => {
+            println!("{}", export::to_json(&data, &schema)?);
+            return Ok(());
         }
-
-        // pid (Passport ID) - a nine-digit number, including leading zeroes
-        if let Some(pid) = &self.pid {
-            if !regex!(r"^(\d{9})$").is_match(&pid) {
-                return Invalid(FieldDoesNotMatchRegex("pid"));
-            }
-        } else {
-            return Invalid(FieldIsNone("pid"));
+        Some("csv") => {
+            print!("{}", export::to_csv(&data, &schema)?);
+            return Ok(());
         }
-
-        // cid (Country ID) - ignored, missing or not.
-
-        // If passed all validation checks, then the Passport is valid
-        Valid
-    }
-
-    fn is_valid_complex(&self) -> bool {
-        self.validate_complex() == Valid
+        Some(_) => bail!(USAGE),
+        None => {}
     }
-}
 
-fn main() -> Result<()> {
-    let path = "data/input.txt";
-    let reader = BufReader::new(File::open(path)?);
-
-    let mut passports = Vec::new();
-    let mut temp: Option<Passport> = None;
-
-    for line in reader.lines() {
-        let line = line?;
-        if line.is_empty() {
-            if let Some(passport) = temp {
-                passports.push(passport);
-                temp = None
+    // Note: list all violations of each invalid passport, then the summary
+    if errors {
+        let mut stats = BatchStats::default();
+        for (i, passport) in data.iter().enumerate() {
+            for warning in schema.warnings(passport) {
+                println!("passport {}: warning: {}", i + 1, warning);
             }
-        } else {
-            let passport = temp.get_or_insert_with(|| Default::default());
-            for item in line.split_whitespace() {
-                let (key, value) = item
-                    .split_once(':')
-                    .ok_or(anyhow!("Bad key-value pair `{}`", item))?;
-                match key {
-                    "byr" => passport.byr = Some(value.to_string()),
-                    "iyr" => passport.iyr = Some(value.to_string()),
-                    "eyr" => passport.eyr = Some(value.to_string()),
-                    "hgt" => passport.hgt = Some(value.to_string()),
-                    "hcl" => passport.hcl = Some(value.to_string()),
-                    "ecl" => passport.ecl = Some(value.to_string()),
-                    "pid" => passport.pid = Some(value.to_string()),
-                    "cid" => passport.cid = Some(value.to_string()),
-                    _ => return Err(anyhow!("Bad key `{}`", key)),
-                }
+            let violations = schema.violations(passport);
+            if !violations.is_empty() {
+                let messages = violations.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+                println!("passport {}: {}", i + 1, messages.join("; "));
             }
+            stats.add(&violations);
         }
+        print!("{}", stats);
+        return Ok(());
     }
 
-    // Push last passport
-    if let Some(passport) = temp {
-        passports.push(passport);
-        temp = None
+    if schema_path.is_some() || require.is_some() {
+        let valid = data.iter().filter(|&p| schema.is_valid(p)).count();
+        println!("[valid] {}", valid);
+        return Ok(());
     }
 
-    // First passport (invalid)
-    assert_eq!(
-        Some(&Passport {
-            byr: Some("1943".to_string()),
-            iyr: Some("2013".to_string()),
-            eyr: Some("2030".to_string()),
-            hgt: Some("151cm".to_string()),
-            hcl: Some("#ceb3a1".to_string()),
-            ecl: Some("grn".to_string()),
-            pid: None,
-            cid: None,
-        }),
-        passports.first()
-    );
-    assert!(!passports.first().unwrap().is_valid_simple());
-    // Last passport (valid)
-    assert_eq!(
-        Some(&Passport {
-            byr: Some("2001".to_string()),
-            iyr: Some("2014".to_string()),
-            eyr: Some("2025".to_string()),
-            hgt: Some("161cm".to_string()),
-            hcl: Some("#4784a2".to_string()),
-            ecl: Some("amb".to_string()),
-            pid: Some("955262336".to_string()),
-            cid: None,
-        }),
-        passports.last()
-    );
-    assert!(passports.last().unwrap().is_valid_simple());
-
-    // Simple validation
-    let valid_simple = passports.iter().filter(|&x| x.is_valid_simple()).count();
-    println!(
-        "Number of valid passports (simple validation): {} of {}",
-        valid_simple,
-        passports.len()
-    );
-
-    // Complex validation
-    let valid_complex = passports.iter().filter(|&x| x.is_valid_complex()).count();
-    println!(
-        "Number of valid passports (complex validation): {} of {}",
-        valid_complex,
-        passports.len()
-    );
+    println!("[part1] {}", Day04::part_one(&data)?);
+    println!("[part2] {}", Day04::part_two(&data)?);
 
     Ok(())
 }
@@ -0,0 +1,440 @@
+//! Declarative validation schema for passports.
+//!
+//! A schema lists one field per line:
+//!
+//! ```text
+//! <name> <required|optional> [regex=<re>] [range=<min>..=<max>] [units=<unit>:<min>..=<max>,...] [enum=<a>,<b>,...]
+//! ```
+//!
+//! - `regex` must match the whole value (it may not contain spaces),
+//! - `range` requires the value to be an integer in the range,
+//! - `units` requires the value to be an integer followed by one of the units,
+//!   in the range of that unit (e.g. `hgt required units=cm:150..=193,in:59..=76`),
+//! - `enum` requires the value to be one of the listed ones.
+//!
+//! Lines starting with `#` followed by a space, and blank lines, are ignored.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+use regex::Regex;
+
+use aoc_common::ParseError;
+
+use crate::Passport;
+
+/// Fields of a complete passport.
+pub const PASSPORT_FIELDS: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+/// Fields of the North Pole credentials: a passport without `cid`.
+pub const NORTH_POLE_CREDENTIALS: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+/// Schema of the North Pole passports, as given by the puzzle.
+pub const PASSPORT_SCHEMA: &str = include_str!("../data/schema.txt");
+
+#[derive(Debug, PartialEq)]
+pub enum ValidationResult {
+    Valid,
+    Invalid(ValidationError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    FieldIsNone(String),
+    FieldDoesNotMatchRegex(String),
+    FieldIsNotInEnum(String),
+    FieldIsNotANumber(String),
+    FieldIsNotInRange(String),
+    FieldHasBadUnit(String),
+    UnitIsNotInRange { field: String, unit: String },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::FieldIsNone(field) => write!(f, "`{}` is missing", field),
+            ValidationError::FieldDoesNotMatchRegex(field) => {
+                write!(f, "`{}` does not match the pattern", field)
+            }
+            ValidationError::FieldIsNotInEnum(field) => {
+                write!(f, "`{}` is not one of the allowed values", field)
+            }
+            ValidationError::FieldIsNotANumber(field) => write!(f, "`{}` is not a number", field),
+            ValidationError::FieldIsNotInRange(field) => write!(f, "`{}` is out of range", field),
+            ValidationError::FieldHasBadUnit(field) => write!(f, "`{}` has a bad unit", field),
+            ValidationError::UnitIsNotInRange { field, unit } => {
+                write!(f, "`{}` in `{}` is out of range", field, unit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl ValidationError {
+    /// Returns the name of the offending field.
+    pub fn field(&self) -> &str {
+        match self {
+            ValidationError::FieldIsNone(field)
+            | ValidationError::FieldDoesNotMatchRegex(field)
+            | ValidationError::FieldIsNotInEnum(field)
+            | ValidationError::FieldIsNotANumber(field)
+            | ValidationError::FieldIsNotInRange(field)
+            | ValidationError::FieldHasBadUnit(field)
+            | ValidationError::UnitIsNotInRange { field, .. } => field,
+        }
+    }
+
+    /// Short name of the violation, for summaries.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ValidationError::FieldIsNone(_) => "missing",
+            ValidationError::FieldDoesNotMatchRegex(_) => "regex",
+            ValidationError::FieldIsNotInEnum(_) => "enum",
+            ValidationError::FieldIsNotANumber(_) => "number",
+            ValidationError::FieldIsNotInRange(_) => "range",
+            ValidationError::FieldHasBadUnit(_) => "unit",
+            ValidationError::UnitIsNotInRange { .. } => "unit range",
+        }
+    }
+}
+
+/// Problems with a passport which do not make it invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    UnknownField(String),
+    DuplicateField(String),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::UnknownField(field) => write!(f, "unknown field `{}`", field),
+            Warning::DuplicateField(field) => {
+                write!(f, "duplicate field `{}`, the first one is used", field)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldRule {
+    pub name: String,
+    pub required: bool,
+    pub regex: Option<Regex>,
+    pub range: Option<RangeInclusive<i64>>,
+    pub units: Vec<(String, RangeInclusive<i64>)>,
+    pub values: Option<Vec<String>>,
+}
+
+impl FieldRule {
+    fn new(name: &str, required: bool) -> Self {
+        FieldRule {
+            name: name.to_string(),
+            required,
+            regex: None,
+            range: None,
+            units: Vec::new(),
+            values: None,
+        }
+    }
+
+    /// Checks the `value` of the field (`None` if it is missing).
+    pub fn validate(&self, value: Option<&str>) -> Result<(), ValidationError> {
+        let value = match value {
+            Some(value) => value,
+            None if self.required => return Err(ValidationError::FieldIsNone(self.name.clone())),
+            None => return Ok(()),
+        };
+        if let Some(re) = &self.regex {
+            if !re.is_match(value) {
+                return Err(ValidationError::FieldDoesNotMatchRegex(self.name.clone()));
+            }
+        }
+        if let Some(values) = &self.values {
+            if !values.iter().any(|v| v == value) {
+                return Err(ValidationError::FieldIsNotInEnum(self.name.clone()));
+            }
+        }
+        if !self.units.is_empty() {
+            let split = value
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(value.len());
+            let (number, unit) = value.split_at(split);
+            let range = self
+                .units
+                .iter()
+                .find(|(u, _)| u == unit)
+                .map(|(_, range)| range)
+                .ok_or_else(|| ValidationError::FieldHasBadUnit(self.name.clone()))?;
+            let n = number
+                .parse::<i64>()
+                .map_err(|_| ValidationError::FieldIsNotANumber(self.name.clone()))?;
+            if !range.contains(&n) {
+                return Err(ValidationError::UnitIsNotInRange {
+                    field: self.name.clone(),
+                    unit: unit.to_string(),
+                });
+            }
+        }
+        if let Some(range) = &self.range {
+            let n = value
+                .parse::<i64>()
+                .map_err(|_| ValidationError::FieldIsNotANumber(self.name.clone()))?;
+            if !range.contains(&n) {
+                return Err(ValidationError::FieldIsNotInRange(self.name.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Schema {
+    pub fields: Vec<FieldRule>,
+}
+
+impl Schema {
+    /// Returns the schema of the North Pole passports.
+    pub fn passport() -> Self {
+        PASSPORT_SCHEMA.parse().unwrap()
+    }
+
+    /// Returns the schema requiring exactly the given fields, e.g. [`NORTH_POLE_CREDENTIALS`].
+    ///
+    /// Fields unknown to the schema are added without constraints.
+    pub fn require(mut self, names: &[&str]) -> Self {
+        for rule in self.fields.iter_mut() {
+            rule.required = names.contains(&rule.name.as_str());
+        }
+        for &name in names {
+            if !self.fields.iter().any(|rule| rule.name == name) {
+                self.fields.push(FieldRule::new(name, true));
+            }
+        }
+        self
+    }
+
+    /// Returns the unknown and the repeated fields of the `passport`.
+    pub fn warnings(&self, passport: &Passport) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let mut seen = Vec::new();
+        for (key, _) in passport.fields() {
+            if seen.contains(&key) {
+                warnings.push(Warning::DuplicateField(key.to_string()));
+                continue;
+            }
+            seen.push(key);
+            if !self.fields.iter().any(|rule| rule.name == key) {
+                warnings.push(Warning::UnknownField(key.to_string()));
+            }
+        }
+        warnings
+    }
+
+    /// Checks that all required fields are present.
+    pub fn has_required(&self, passport: &Passport) -> bool {
+        self.fields
+            .iter()
+            .filter(|rule| rule.required)
+            .all(|rule| passport.get(&rule.name).is_some())
+    }
+
+    /// Checks all fields, returning the first violation.
+    pub fn validate(&self, passport: &Passport) -> ValidationResult {
+        for rule in &self.fields {
+            if let Err(e) = rule.validate(passport.get(&rule.name)) {
+                return ValidationResult::Invalid(e);
+            }
+        }
+        ValidationResult::Valid
+    }
+
+    /// Checks all fields, returning every violation (at most one per field).
+    pub fn violations(&self, passport: &Passport) -> Vec<ValidationError> {
+        self.fields
+            .iter()
+            .filter_map(|rule| rule.validate(passport.get(&rule.name)).err())
+            .collect()
+    }
+
+    pub fn is_valid(&self, passport: &Passport) -> bool {
+        self.validate(passport) == ValidationResult::Valid
+    }
+}
+
+impl FromStr for Schema {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = Vec::new();
+        for line in s.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed == "#" || trimmed.starts_with("# ") {
+                continue;
+            }
+            let rule = parse_rule(line).map_err(|e| ParseError::locate(e, s, line))?;
+            fields.push(rule);
+        }
+        Ok(Schema { fields })
+    }
+}
+
+fn parse_rule(line: &str) -> Result<FieldRule, ParseError> {
+    let mut words = line.split_whitespace();
+    let name = words.next().unwrap();
+    let required = match words.next() {
+        Some("required") => true,
+        Some("optional") => false,
+        Some(word) => {
+            return Err(ParseError::at(
+                line,
+                word,
+                "Expected `required` or `optional`",
+            ))
+        }
+        None => {
+            return Err(ParseError::at(
+                line,
+                name,
+                "Expected `<name> required|optional`",
+            ))
+        }
+    };
+    let mut rule = FieldRule::new(name, required);
+    for word in words {
+        let (key, value) = word
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(line, word, "Expected `<constraint>=<value>`"))?;
+        match key {
+            "regex" => {
+                // Note: the regex must match the whole value
+                let re = Regex::new(&format!("^(?:{})$", value))
+                    .map_err(|e| ParseError::at(line, value, e.to_string()))?;
+                rule.regex = Some(re);
+            }
+            "range" => rule.range = Some(parse_range(line, value)?),
+            "units" => {
+                for item in value.split(',') {
+                    let (unit, range) = item.split_once(':').ok_or_else(|| {
+                        ParseError::at(line, item, "Expected `<unit>:<min>..=<max>`")
+                    })?;
+                    rule.units
+                        .push((unit.to_string(), parse_range(line, range)?));
+                }
+            }
+            "enum" => rule.values = Some(value.split(',').map(str::to_string).collect()),
+            _ => {
+                return Err(ParseError::at(
+                    line,
+                    key,
+                    format!("Unknown constraint `{}`", key),
+                ))
+            }
+        }
+    }
+    Ok(rule)
+}
+
+fn parse_range(line: &str, s: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    let (min, max) = s
+        .split_once("..=")
+        .ok_or_else(|| ParseError::at(line, s, "Expected `<min>..=<max>`"))?;
+    let number = |x: &str| {
+        x.parse::<i64>()
+            .map_err(|e| ParseError::at(line, x, format!("Bad number `{}`: {}", x, e)))
+    };
+    Ok(number(min)?..=number(max)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_rules() {
+        let schema = Schema::passport();
+        let rule = |name: &str| schema.fields.iter().find(|r| r.name == name).unwrap();
+        assert_eq!(rule("byr").validate(Some("2002")), Ok(()));
+        assert_eq!(
+            rule("byr").validate(Some("2003")),
+            Err(ValidationError::FieldIsNotInRange("byr".into()))
+        );
+        assert_eq!(rule("hgt").validate(Some("60in")), Ok(()));
+        assert_eq!(rule("hgt").validate(Some("190cm")), Ok(()));
+        assert_eq!(
+            rule("hgt").validate(Some("190in")),
+            Err(ValidationError::UnitIsNotInRange {
+                field: "hgt".into(),
+                unit: "in".into()
+            })
+        );
+        assert_eq!(
+            rule("hgt").validate(Some("190")),
+            Err(ValidationError::FieldHasBadUnit("hgt".into()))
+        );
+        assert_eq!(rule("hcl").validate(Some("#123abc")), Ok(()));
+        assert!(rule("hcl").validate(Some("#123abz")).is_err());
+        assert!(rule("hcl").validate(Some("123abc")).is_err());
+        assert_eq!(rule("ecl").validate(Some("brn")), Ok(()));
+        assert_eq!(
+            rule("ecl").validate(Some("wat")),
+            Err(ValidationError::FieldIsNotInEnum("ecl".into()))
+        );
+        assert_eq!(rule("pid").validate(Some("000000001")), Ok(()));
+        assert!(rule("pid").validate(Some("0123456789")).is_err());
+        assert_eq!(rule("cid").validate(None), Ok(()));
+        assert_eq!(
+            rule("pid").validate(None),
+            Err(ValidationError::FieldIsNone("pid".into()))
+        );
+    }
+
+    #[test]
+    fn test_required_sets_and_warnings() {
+        let passport = Passport::from_fields(vec![
+            ("byr", "1980"),
+            ("iyr", "2012"),
+            ("eyr", "2030"),
+            ("hgt", "74in"),
+            ("hcl", "#623a2f"),
+            ("ecl", "grn"),
+            ("pid", "087499704"),
+            ("tag", "x"),
+            ("byr", "1900"),
+        ]);
+        let schema = Schema::passport();
+        assert!(schema.is_valid(&passport));
+        assert_eq!(
+            schema.warnings(&passport),
+            vec![
+                Warning::UnknownField("tag".into()),
+                Warning::DuplicateField("byr".into()),
+            ]
+        );
+        let full = Schema::passport().require(PASSPORT_FIELDS);
+        assert_eq!(
+            full.violations(&passport),
+            vec![ValidationError::FieldIsNone("cid".into())]
+        );
+        let tagged = Schema::passport().require(&["pid", "tag"]);
+        assert!(tagged.is_valid(&passport));
+        assert!(tagged
+            .warnings(&passport)
+            .iter()
+            .all(|w| matches!(w, Warning::DuplicateField(_))));
+    }
+
+    #[test]
+    fn test_schema_errors() {
+        let e = "byr required\nhgt required units=cm:150-193\n"
+            .parse::<Schema>()
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!((e.line, e.column), (2, 23));
+        assert_eq!(e.message, "Expected `<min>..=<max>`");
+    }
+}
@@ -0,0 +1,102 @@
+//! Aggregated validation statistics over a batch of passports.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::schema::ValidationError;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchStats {
+    pub total: usize,
+    pub valid: usize,
+    /// Number of violations per field and kind of violation
+    pub violations: BTreeMap<(String, &'static str), usize>,
+}
+
+impl BatchStats {
+    /// Accounts for a passport with the given `violations`.
+    pub fn add(&mut self, violations: &[ValidationError]) {
+        self.total += 1;
+        if violations.is_empty() {
+            self.valid += 1;
+        }
+        for e in violations {
+            *self
+                .violations
+                .entry((e.field().to_string(), e.kind()))
+                .or_insert(0) += 1;
+        }
+    }
+
+    pub fn invalid(&self) -> usize {
+        self.total - self.valid
+    }
+
+    /// Returns the number of violations per field.
+    pub fn by_field(&self) -> BTreeMap<&str, usize> {
+        let mut by_field = BTreeMap::new();
+        for ((field, _), n) in &self.violations {
+            *by_field.entry(field.as_str()).or_insert(0) += n;
+        }
+        by_field
+    }
+}
+
+impl fmt::Display for BatchStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Passports: {} total, {} valid, {} invalid",
+            self.total,
+            self.valid,
+            self.invalid()
+        )?;
+        for (field, n) in self.by_field() {
+            writeln!(f, "  {}: {} violations", field, n)?;
+            for ((_, kind), m) in self.violations.iter().filter(|((x, _), _)| x == field) {
+                writeln!(f, "    {:<10} {}", kind, m)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::{Day04, Schema};
+
+    #[test]
+    fn test_all_violations() {
+        let input = "\
+hgt:59cm ecl:zzz byr:2003 iyr:2015 eyr:2025 hcl:#123abc
+
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f
+";
+        let passports = Day04::parse(input).unwrap();
+        let schema = Schema::passport();
+        let violations = schema.violations(&passports[0]);
+        assert_eq!(
+            violations,
+            vec![
+                ValidationError::FieldIsNotInRange("byr".into()),
+                ValidationError::UnitIsNotInRange {
+                    field: "hgt".into(),
+                    unit: "cm".into()
+                },
+                ValidationError::FieldIsNotInEnum("ecl".into()),
+                ValidationError::FieldIsNone("pid".into()),
+            ]
+        );
+
+        let mut stats = BatchStats::default();
+        for passport in &passports {
+            stats.add(&schema.violations(passport));
+        }
+        assert_eq!((stats.total, stats.valid, stats.invalid()), (2, 1, 1));
+        assert_eq!(stats.by_field().len(), 4);
+        assert_eq!(stats.violations[&("hgt".to_string(), "unit range")], 1);
+    }
+}
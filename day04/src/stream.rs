@@ -0,0 +1,124 @@
+//! Streaming reader of passports, holding only one passport in memory at a time.
+
+use std::io::BufRead;
+
+use anyhow::Result;
+
+use crate::schema::Schema;
+use crate::stats::BatchStats;
+use crate::Passport;
+
+/// Iterator over the blank-line separated passports read from any [`BufRead`]
+/// (a file, stdin, a decompressing stream, ...).
+///
+/// Parse errors point at the line of the whole stream; reading stops after any error.
+pub struct PassportReader<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    done: bool,
+}
+
+impl<R: BufRead> PassportReader<R> {
+    pub fn new(reader: R) -> Self {
+        PassportReader {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for PassportReader<R> {
+    type Item = Result<Passport>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut passport: Option<Passport> = None;
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    return passport.map(Ok);
+                }
+                Ok(_) => self.line_number += 1,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+            let line = self.line.trim_end_matches(&['\n', '\r'][..]);
+            if line.trim().is_empty() {
+                if passport.is_some() {
+                    return passport.map(Ok);
+                }
+                // Skip leading and repeated blank lines
+                continue;
+            }
+            let current = passport.get_or_insert_with(Passport::default);
+            if let Err(mut e) = current.add_line(line) {
+                e.line = self.line_number;
+                self.done = true;
+                return Some(Err(e.into()));
+            }
+        }
+    }
+}
+
+/// Folds the stream of passports into validation statistics.
+pub fn validate<I>(passports: I, schema: &Schema) -> Result<BatchStats>
+where
+    I: IntoIterator<Item = Result<Passport>>,
+{
+    passports
+        .into_iter()
+        .try_fold(BatchStats::default(), |mut stats, passport| {
+            stats.add(&schema.violations(&passport?));
+            Ok(stats)
+        })
+}
+
+/// Counts the passports in the stream satisfying the predicate `f`.
+pub fn count<I, F>(passports: I, mut f: F) -> Result<usize>
+where
+    I: IntoIterator<Item = Result<Passport>>,
+    F: FnMut(&Passport) -> bool,
+{
+    passports
+        .into_iter()
+        .try_fold(0, |n, passport| Ok(n + f(&passport?) as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::{BufReader, Cursor};
+
+    use aoc_common::ParseError;
+
+    use super::*;
+
+    #[test]
+    fn test_stream_input() {
+        let schema = Schema::passport();
+        let open = || PassportReader::new(BufReader::new(File::open("data/input.txt").unwrap()));
+        assert_eq!(count(open(), |p| schema.has_required(p)).unwrap(), 208);
+        let stats = validate(open(), &schema).unwrap();
+        assert_eq!((stats.total, stats.valid), (285, 167));
+    }
+
+    #[test]
+    fn test_stream_error() {
+        let input = "\n\nbyr:1937 iyr:2017\r\n\r\n\r\necl:gry\npid 860033327\n\nhgt:183cm\n";
+        let mut reader = PassportReader::new(Cursor::new(input));
+        assert_eq!(reader.next().unwrap().unwrap().fields().count(), 2);
+        let e = reader.next().unwrap().unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (7, 1));
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,201 @@
+//! Seat codes: binary space partitioning of the rows and columns of an aircraft.
+
+use anyhow::{ensure, Result};
+
+use aoc_common::ParseError;
+
+/// Layout of an aircraft: the numbers of bits of the rows and columns,
+/// and the letters coding the bits `0` and `1` of each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    row_bits: u32,
+    col_bits: u32,
+    row_letters: [char; 2],
+    col_letters: [char; 2],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Seat {
+    pub row: u32,
+    pub col: u32,
+    pub id: u32,
+}
+
+impl Layout {
+    /// Maximal length of the seat codes: 65536 seats is more than any aircraft has.
+    pub const MAX_BITS: u32 = 16;
+
+    /// Layout of the puzzle: 128 rows (`F` front, `B` back) and 8 columns (`L` left, `R` right).
+    pub fn north_pole() -> Self {
+        Layout::new(7, 3, ['F', 'B'], ['L', 'R']).unwrap()
+    }
+
+    pub fn new(
+        row_bits: u32,
+        col_bits: u32,
+        row_letters: [char; 2],
+        col_letters: [char; 2],
+    ) -> Result<Self> {
+        let bits = row_bits
+            .checked_add(col_bits)
+            .filter(|&bits| bits <= Layout::MAX_BITS);
+        ensure!(
+            bits.is_some(),
+            "Seat codes of {} + {} bits are too long, at most {} bits are allowed",
+            row_bits,
+            col_bits,
+            Layout::MAX_BITS
+        );
+        ensure!(
+            row_letters[0] != row_letters[1] && col_letters[0] != col_letters[1],
+            "Letters of the bits 0 and 1 must differ"
+        );
+        Ok(Layout {
+            row_bits,
+            col_bits,
+            row_letters,
+            col_letters,
+        })
+    }
+
+    pub fn rows(&self) -> u32 {
+        1 << self.row_bits
+    }
+
+    pub fn cols(&self) -> u32 {
+        1 << self.col_bits
+    }
+
+    /// Returns the number of seats, which is also the bound of the seat ids.
+    pub fn seats(&self) -> u32 {
+        self.rows() * self.cols()
+    }
+
+    pub fn code_len(&self) -> usize {
+        (self.row_bits + self.col_bits) as usize
+    }
+
+    /// Returns the seat at the given `row` and `col`.
+    pub fn seat(&self, row: u32, col: u32) -> Result<Seat> {
+        ensure!(
+            row < self.rows(),
+            "Row {} is out of range 0..{}",
+            row,
+            self.rows()
+        );
+        ensure!(
+            col < self.cols(),
+            "Column {} is out of range 0..{}",
+            col,
+            self.cols()
+        );
+        Ok(Seat {
+            row,
+            col,
+            id: row << self.col_bits | col,
+        })
+    }
+
+    /// Returns the seat with the given `id`.
+    pub fn seat_by_id(&self, id: u32) -> Result<Seat> {
+        ensure!(
+            id < self.seats(),
+            "Seat id {} is out of range 0..{}",
+            id,
+            self.seats()
+        );
+        self.seat(id >> self.col_bits, id & (self.cols() - 1))
+    }
+
+    /// Decodes a code like `FBFBBFFRLR` into its seat.
+    pub fn decode(&self, code: &str) -> Result<Seat, ParseError> {
+        let len = code.chars().count();
+        if len != self.code_len() {
+            return Err(ParseError::at(
+                code,
+                code,
+                format!("Expected {} chars, found {}", self.code_len(), len),
+            ));
+        }
+        let mut row = 0;
+        let mut col = 0;
+        for (k, (i, c)) in code.char_indices().enumerate() {
+            let (value, letters) = if k < self.row_bits as usize {
+                (&mut row, self.row_letters)
+            } else {
+                (&mut col, self.col_letters)
+            };
+            let bit = letters.iter().position(|&x| x == c).ok_or_else(|| {
+                ParseError::at(
+                    code,
+                    &code[i..],
+                    format!(
+                        "Bad char `{}`, expected `{}` or `{}`",
+                        c, letters[0], letters[1]
+                    ),
+                )
+            })?;
+            *value = *value << 1 | bit as u32;
+        }
+        Ok(Seat {
+            row,
+            col,
+            id: row << self.col_bits | col,
+        })
+    }
+
+    /// Encodes the seat at the given `row` and `col` back into its code.
+    pub fn encode(&self, row: u32, col: u32) -> Result<String> {
+        let seat = self.seat(row, col)?;
+        let bits = |value: u32, n: u32, letters: [char; 2]| {
+            (0..n)
+                .rev()
+                .map(move |i| letters[(value >> i & 1) as usize])
+        };
+        Ok(bits(seat.row, self.row_bits, self.row_letters)
+            .chain(bits(seat.col, self.col_bits, self.col_letters))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_north_pole() {
+        let layout = Layout::north_pole();
+        let cases = [
+            ("FBFBBFFRLR", 44, 5, 357),
+            ("BFFFBBFRRR", 70, 7, 567),
+            ("FFFBBBFRRR", 14, 7, 119),
+            ("BBFFBBFRLL", 102, 4, 820),
+        ];
+        for &(code, row, col, id) in &cases {
+            assert_eq!(layout.decode(code).unwrap(), Seat { row, col, id });
+            assert_eq!(layout.encode(row, col).unwrap(), code);
+            assert_eq!(layout.seat_by_id(id).unwrap(), Seat { row, col, id });
+        }
+        let e = layout.decode("FBFBXFFRLR").unwrap_err();
+        assert_eq!(e.column, 5);
+        let e = layout.decode("FBFBBFFRLB").unwrap_err();
+        assert_eq!(e.message, "Bad char `B`, expected `L` or `R`");
+        assert!(layout.decode("FBF").is_err());
+        assert!(layout.encode(128, 0).is_err());
+    }
+
+    #[test]
+    fn test_custom_layout() {
+        // 4 rows of 4 seats, coded by digits
+        let layout = Layout::new(2, 2, ['0', '1'], ['a', 'b']).unwrap();
+        for id in 0..layout.seats() {
+            let seat = layout.seat_by_id(id).unwrap();
+            let code = layout.encode(seat.row, seat.col).unwrap();
+            assert_eq!(layout.decode(&code).unwrap(), seat);
+        }
+        assert_eq!(layout.encode(2, 1).unwrap(), "10ab");
+        assert!(Layout::new(2, 2, ['0', '0'], ['a', 'b']).is_err());
+        assert!(Layout::new(10, 7, ['0', '1'], ['a', 'b']).is_err());
+        assert!(Layout::new(u32::MAX, 1, ['0', '1'], ['a', 'b']).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use anyhow::{anyhow, Result};
+use log::info;
+
+use aoc_common::input::parse_lines_with;
+use aoc_common::Solution;
+
+pub use crate::codec::{Layout, Seat};
+pub use crate::map::SeatMap;
+
+pub mod codec;
+pub mod map;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Seat>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let layout = Layout::north_pole();
+        parse_lines_with(input, |line| layout.decode(line))
+    }
+
+    fn part_one(data: &Self::Input) -> Result<Self::AnswerOne> {
+        let ids = seat_ids(data);
+        let min_id = ids
+            .iter()
+            .min()
+            .ok_or_else(|| anyhow!("No boarding passes"))?;
+        let max_id = ids
+            .iter()
+            .max()
+            .ok_or_else(|| anyhow!("No boarding passes"))?;
+        info!("Minimum ID: {}", min_id);
+        info!("Maximum ID: {}", max_id);
+        Ok(*max_id)
+    }
+
+    fn part_two(data: &Self::Input) -> Result<Self::AnswerTwo> {
+        let map = SeatMap::new(&Layout::north_pole(), data)?;
+        match map.missing_seats()[..] {
+            [seat] => {
+                info!("Missing ID: {}", seat.id);
+                Ok(seat.id)
+            }
+            [] => Err(anyhow!("No missing ID")),
+            ref seats => Err(anyhow!("Ambiguous missing ID: {} candidates", seats.len())),
+        }
+    }
+}
+
+fn seat_ids(data: &[Seat]) -> Vec<u32> {
+    data.iter().map(|seat| seat.id).collect()
+}
@@ -1,47 +1,52 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::env;
 
-use anyhow::{anyhow, Result};
+use anyhow::{bail, Result};
+
+use aoc_common::input::read_input;
+use aoc_common::{logging, with_file, Solution};
+use day05::{Day05, Layout, SeatMap};
+
+const USAGE: &str = "Usage: day05 [--map]";
 
 fn main() -> Result<()> {
+    logging::init_from_env()?;
+
+    let mut show_map = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--map" => show_map = true,
+            _ => bail!(USAGE),
+        }
+    }
+
     let path = "data/input.txt";
-    let data: Vec<_> = BufReader::new(File::open(path)?)
-        .lines()
-        .filter_map(|x| x.ok())
-        .map(|line| {
-            assert_eq!(line.len(), 10);
-            let mut chars = line.chars();
-            let row: i32 = (0..7)
-                .rev()
-                .map(|i| match chars.next().unwrap() {
-                    'B' => 1 << i,
-                    'F' => 0,
-                    _ => panic!("bad char"),
-                })
-                .sum();
-            let col: i32 = (0..3)
-                .rev()
-                .map(|j| match chars.next().unwrap() {
-                    'R' => 1 << j,
-                    'L' => 0,
-                    _ => panic!("bad char"),
-                })
-                .sum();
-            (row, col)
-        })
-        .collect();
-    let ids: Vec<_> = data.iter().map(|(row, col)| row * 8 + col).collect();
-    let min_id = ids.iter().min().unwrap();
-    let max_id = ids.iter().max().unwrap();
-    println!("Minimum ID: {}", min_id);
-    println!("Maximum ID: {}", max_id);
-
-    for x in *min_id..*max_id {
-        if !ids.contains(&x) {
-            println!("Missing ID: {}", x);
-            break;
+    let input = read_input(path)?;
+    let data = Day05::parse(&input).map_err(|e| with_file(e, path))?;
+
+    if show_map {
+        let layout = Layout::north_pole();
+        let map = SeatMap::new(&layout, &data)?;
+        print!("{}", map);
+        println!(
+            "[occupied] {} of {} seats, {} boarding passes",
+            map.occupied(),
+            layout.seats(),
+            data.len()
+        );
+        for gap in map.gaps() {
+            println!("[gap] ids {}..{} ({} free)", gap.start, gap.end, gap.len());
         }
+        for (seat, n) in map.duplicates() {
+            println!(
+                "[duplicate] id {} (row {}, col {}): {} passes",
+                seat.id, seat.row, seat.col, n
+            );
+        }
+        return Ok(());
     }
 
+    println!("[part1] {}", Day05::part_one(&data)?);
+    println!("[part2] {}", Day05::part_two(&data)?);
+
     Ok(())
 }
@@ -0,0 +1,142 @@
+//! Seat map of the aircraft: occupancy of each seat by the boarding passes.
+
+use std::fmt;
+use std::ops::Range;
+
+use anyhow::{ensure, Result};
+
+use crate::codec::{Layout, Seat};
+
+/// Number of boarding passes for each seat of the aircraft, indexed by seat id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeatMap {
+    layout: Layout,
+    passes: Vec<usize>,
+}
+
+impl SeatMap {
+    /// Builds the map in a single pass over the `seats`,
+    /// failing on any seat which does not belong to the `layout`.
+    pub fn new(layout: &Layout, seats: &[Seat]) -> Result<Self> {
+        let mut passes = vec![0; layout.seats() as usize];
+        for seat in seats {
+            ensure!(
+                layout.seat_by_id(seat.id).ok().as_ref() == Some(seat),
+                "Seat {:?} does not belong to the layout",
+                seat
+            );
+            passes[seat.id as usize] += 1;
+        }
+        Ok(SeatMap {
+            layout: layout.clone(),
+            passes,
+        })
+    }
+
+    pub fn is_occupied(&self, id: u32) -> bool {
+        self.passes[id as usize] > 0
+    }
+
+    /// Returns the number of occupied seats.
+    pub fn occupied(&self) -> usize {
+        self.passes.iter().filter(|&&n| n > 0).count()
+    }
+
+    /// Returns the range of ids from the first to the last occupied seat.
+    pub fn span(&self) -> Option<Range<u32>> {
+        let first = self.passes.iter().position(|&n| n > 0)?;
+        let last = self.passes.iter().rposition(|&n| n > 0)?;
+        Some(first as u32..last as u32 + 1)
+    }
+
+    /// Returns the runs of free seats between the first and the last occupied seat.
+    pub fn gaps(&self) -> Vec<Range<u32>> {
+        let span = match self.span() {
+            Some(span) => span,
+            None => return Vec::new(),
+        };
+        let mut gaps = Vec::new();
+        let mut start = None;
+        for id in span {
+            match (self.is_occupied(id), start) {
+                (false, None) => start = Some(id),
+                (true, Some(s)) => {
+                    gaps.push(s..id);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        gaps
+    }
+
+    /// Returns the seats with more than one boarding pass, with the number of passes.
+    pub fn duplicates(&self) -> Vec<(Seat, usize)> {
+        self.passes
+            .iter()
+            .enumerate()
+            .filter(|&(_, &n)| n > 1)
+            .map(|(id, &n)| (self.layout.seat_by_id(id as u32).unwrap(), n))
+            .collect()
+    }
+
+    /// Returns the free seats whose both neighbours (by id) are occupied.
+    pub fn missing_seats(&self) -> Vec<Seat> {
+        self.gaps()
+            .into_iter()
+            .filter(|gap| gap.len() == 1)
+            .map(|gap| self.layout.seat_by_id(gap.start).unwrap())
+            .collect()
+    }
+}
+
+/// Renders one line per row: `#` for an occupied seat, `.` for a free one
+/// and the number of passes for a seat with several of them.
+impl fmt::Display for SeatMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cols = self.layout.cols() as usize;
+        let header = (0..cols)
+            .map(|col| std::char::from_digit(col as u32 % 10, 10).unwrap())
+            .collect::<String>();
+        writeln!(f, "{:>4} {}", "", header)?;
+        for (row, passes) in self.passes.chunks(cols).enumerate() {
+            let seats = passes
+                .iter()
+                .map(|&n| match n {
+                    0 => '.',
+                    1 => '#',
+                    n => std::char::from_digit(n.min(9) as u32, 10).unwrap(),
+                })
+                .collect::<String>();
+            writeln!(f, "{:>4} {}", row, seats)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gaps_and_duplicates() {
+        // 4 rows of 2 seats
+        let layout = Layout::new(2, 1, ['F', 'B'], ['L', 'R']).unwrap();
+        let seats = ["FFR", "FBL", "FBL", "BFR", "BBL"]
+            .iter()
+            .map(|code| layout.decode(code).unwrap())
+            .collect::<Vec<_>>();
+        let map = SeatMap::new(&layout, &seats).unwrap();
+        assert_eq!(map.occupied(), 4);
+        assert_eq!(map.span(), Some(1..7));
+        assert_eq!(map.gaps(), vec![3..5]);
+        assert!(map.missing_seats().is_empty());
+        assert_eq!(map.duplicates(), vec![(layout.seat(1, 0).unwrap(), 2)]);
+        assert_eq!(
+            map.to_string(),
+            "     01\n   0 .#\n   1 2.\n   2 .#\n   3 #.\n"
+        );
+        let seat = Layout::north_pole().decode("FBFBBFFRLR").unwrap();
+        assert!(SeatMap::new(&layout, &[seat]).is_err());
+    }
+}
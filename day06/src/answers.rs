@@ -0,0 +1,128 @@
+//! Set of the questions `a`..=`z` answered "yes", as a 26-bit bitset.
+
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor};
+
+use serde::{Serialize, Serializer};
+
+use aoc_common::ParseError;
+
+pub const QUESTIONS: usize = 26;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Answers(u32);
+
+impl Answers {
+    /// Set of all the questions.
+    pub const ALL: Answers = Answers((1 << QUESTIONS) - 1);
+
+    /// Parses the answers of one person, e.g. `abcx`, pointing any error into the `input`.
+    pub fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+        for (i, c) in line.char_indices() {
+            if !c.is_ascii_lowercase() {
+                return Err(ParseError::at(
+                    input,
+                    &line[i..],
+                    format!("Bad question `{}`, expected `a`..=`z`", c),
+                ));
+            }
+            answers.insert(c);
+        }
+        Ok(answers)
+    }
+
+    /// Returns the index (`0` for `a`) of the question `c`, if it is one.
+    pub fn index(c: char) -> Option<usize> {
+        if c.is_ascii_lowercase() {
+            Some((c as u8 - b'a') as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn question(index: usize) -> char {
+        assert!(index < QUESTIONS, "question index {} out of range", index);
+        (b'a' + index as u8) as char
+    }
+
+    /// Adds the question `c`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c` is not in `a`..=`z`.
+    pub fn insert(&mut self, c: char) {
+        let index = Answers::index(c).unwrap_or_else(|| panic!("bad question `{}`", c));
+        self.0 |= 1 << index;
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        Answers::index(c).is_some_and(|index| self.0 >> index & 1 == 1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the questions in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = char> + '_ {
+        (0..QUESTIONS)
+            .filter(move |&i| self.0 >> i & 1 == 1)
+            .map(Answers::question)
+    }
+}
+
+impl BitOr for Answers {
+    type Output = Answers;
+
+    fn bitor(self, rhs: Answers) -> Answers {
+        Answers(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for Answers {
+    type Output = Answers;
+
+    fn bitand(self, rhs: Answers) -> Answers {
+        Answers(self.0 & rhs.0)
+    }
+}
+
+impl BitXor for Answers {
+    type Output = Answers;
+
+    fn bitxor(self, rhs: Answers) -> Answers {
+        Answers(self.0 ^ rhs.0)
+    }
+}
+
+impl std::iter::FromIterator<char> for Answers {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut answers = Answers::default();
+        for c in iter {
+            answers.insert(c);
+        }
+        answers
+    }
+}
+
+/// Displays the questions in alphabetical order, e.g. `abcx`.
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.iter() {
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the answers as a string of questions, e.g. `"abcx"`.
+impl Serialize for Answers {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::Result;
+use log::info;
+
+use aoc_common::input::groups;
+use aoc_common::Solution;
+
+pub use crate::answers::{Answers, QUESTIONS};
+pub use crate::report::Report;
+
+pub mod answers;
+pub mod report;
+
+/// Answers of each member of a group.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub members: Vec<Answers>,
+}
+
+impl Item {
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns the questions answered by anyone.
+    pub fn union(&self) -> Answers {
+        self.members.iter().fold(Answers::default(), |a, &b| a | b)
+    }
+
+    /// Returns the questions answered by everyone.
+    pub fn intersection(&self) -> Answers {
+        self.members.iter().fold(Answers::ALL, |a, &b| a & b) & self.union()
+    }
+
+    /// Returns the questions answered by an odd number of members.
+    pub fn symmetric_difference(&self) -> Answers {
+        self.members.iter().fold(Answers::default(), |a, &b| a ^ b)
+    }
+
+    /// Returns the number of members answering each question, `a` first.
+    pub fn frequencies(&self) -> [usize; QUESTIONS] {
+        let mut frequencies = [0; QUESTIONS];
+        for answers in &self.members {
+            for c in answers.iter() {
+                frequencies[Answers::index(c).unwrap()] += 1;
+            }
+        }
+        frequencies
+    }
+
+    /// Returns the questions answered by at least `k` members.
+    pub fn at_least(&self, k: usize) -> Answers {
+        self.select(|n| n >= k)
+    }
+
+    /// Returns the questions answered by exactly `k` members.
+    pub fn exactly(&self, k: usize) -> Answers {
+        self.select(|n| n == k)
+    }
+
+    pub fn exactly_one(&self) -> Answers {
+        self.exactly(1)
+    }
+
+    /// Returns the answered questions whose number of answers satisfies `f`.
+    fn select<F: Fn(usize) -> bool>(&self, f: F) -> Answers {
+        self.frequencies()
+            .iter()
+            .enumerate()
+            .filter(|&(_, &n)| n > 0 && f(n))
+            .map(|(i, _)| Answers::question(i))
+            .collect()
+    }
+}
+
+fn parse_groups(input: &str) -> Result<Vec<Item>> {
+    groups(input.lines())
+        .map(|lines| {
+            let members = lines
+                .iter()
+                .map(|line| Answers::parse(input, line))
+                .collect::<Result<_, _>>()?;
+            Ok(Item { members })
+        })
+        .collect()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Item>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_groups(input)
+    }
+
+    fn part_one(data: &Self::Input) -> Result<Self::AnswerOne> {
+        let total_union: usize = data.iter().map(|x| x.union().len()).sum();
+        info!("Total union sum: {}", total_union);
+        Ok(total_union)
+    }
+
+    fn part_two(data: &Self::Input) -> Result<Self::AnswerTwo> {
+        let total_intersection: usize = data.iter().map(|x| x.intersection().len()).sum();
+        info!("Total intersection sum: {}", total_intersection);
+        Ok(total_intersection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queries() {
+        let input = "abc\nabd\nax\n\nb\n";
+        let data = Day06::parse(input).unwrap();
+        let group = &data[0];
+        assert_eq!(group.size(), 3);
+        assert_eq!(group.union().to_string(), "abcdx");
+        assert_eq!(group.intersection().to_string(), "a");
+        assert_eq!(group.at_least(2).to_string(), "ab");
+        assert_eq!(group.exactly_one().to_string(), "cdx");
+        assert_eq!(group.symmetric_difference().to_string(), "acdx");
+        assert_eq!(group.frequencies()[..4], [3, 2, 1, 1]);
+        assert_eq!(data[1].intersection().to_string(), "b");
+
+        let e = Day06::parse("abc\n\nab1\n").unwrap_err();
+        let e = e.downcast::<aoc_common::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (3, 3));
+    }
+}
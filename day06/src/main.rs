@@ -1,62 +1,50 @@
-use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::env;
 
-use anyhow::{anyhow, Result};
+use anyhow::{bail, Context, Result};
 
-#[derive(Debug, Default)]
-struct Item {
-    union: HashSet<char>,
-    intersection: HashSet<char>,
-}
+use aoc_common::input::read_input;
+use aoc_common::{logging, with_file, Solution};
+use day06::{Day06, Report};
+
+const USAGE: &str = "Usage: day06 [--report] [--export json|csv|questions-csv]";
 
 fn main() -> Result<()> {
-    let path = "data/input.txt";
-    let reader = BufReader::new(File::open(path)?);
-
-    let mut data: Vec<Item> = Vec::new();
-    let mut temp: Option<Item> = None;
-
-    for line in reader.lines() {
-        let line = line?;
-        if line.is_empty() {
-            if let Some(item) = temp {
-                data.push(item);
-                temp = None
-            }
-        } else {
-            let chars = line.chars().collect();
-            if let Some(ref mut item) = temp {
-                // Update union
-                item.union = item.union.union(&chars).copied().collect();
-                // Update intersection
-                item.intersection = item.intersection.intersection(&chars).copied().collect();
-            } else {
-                // Create new item using current chars
-                // Note: only one `.clone()` is necessary here for `chars`,
-                //  another one can `move`, but I do clone both just for consistency.
-                temp = Some(Item {
-                    union: chars.clone(),
-                    intersection: chars.clone(),
-                });
-            }
+    logging::init_from_env()?;
+
+    let mut show_report = false;
+    let mut export_format = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--report" => show_report = true,
+            "--export" => export_format = Some(args.next().context(USAGE)?),
+            _ => bail!(USAGE),
         }
     }
 
-    // Note: do not forget to push the last one!
-    if let Some(item) = temp {
-        data.push(item);
-        temp = None
+    let path = "data/input.txt";
+    let input = read_input(path)?;
+    let data = Day06::parse(&input).map_err(|e| with_file(e, path))?;
+
+    // Note: the CSV export holds one table, either of the groups or of the questions
+    if let Some(format) = export_format {
+        let report = Report::new(&data);
+        match format.as_str() {
+            "json" => println!("{}", report.to_json()?),
+            "csv" => print!("{}", report.groups_csv()?),
+            "questions-csv" => print!("{}", report.questions_csv()?),
+            _ => bail!(USAGE),
+        }
+        return Ok(());
     }
 
-    // data.iter().for_each(|x| {
-    //     println!("{:?}", x);
-    // });
+    if show_report {
+        print!("{}", Report::new(&data));
+        return Ok(());
+    }
 
-    let total_union: usize = data.iter().map(|x| x.union.len()).sum();
-    let total_intersection: usize = data.iter().map(|x| x.intersection.len()).sum();
-    println!("Total union sum: {}", total_union);
-    println!("Total intersection sum: {}", total_intersection);
+    println!("[part1] {}", Day06::part_one(&data)?);
+    println!("[part2] {}", Day06::part_two(&data)?);
 
     Ok(())
 }
@@ -0,0 +1,186 @@
+//! Customs report: a summary of each group and the counts of each question.
+
+use std::fmt;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::answers::{Answers, QUESTIONS};
+use crate::Item;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GroupSummary {
+    /// 1-based number of the group in the input
+    pub group: usize,
+    pub size: usize,
+    pub union: Answers,
+    pub intersection: Answers,
+    /// Questions answered by the most members (ties included)
+    pub most_common: Answers,
+    /// Answered questions with the fewest answers (ties included)
+    pub least_common: Answers,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QuestionCounts {
+    pub question: char,
+    /// Number of people answering the question
+    pub people: usize,
+    /// Number of groups where anyone answered the question
+    pub groups: usize,
+    /// Number of groups where everyone answered the question
+    pub unanimous: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub groups: Vec<GroupSummary>,
+    pub questions: Vec<QuestionCounts>,
+}
+
+impl GroupSummary {
+    /// Summarizes the group `item` with its per-question `frequencies`.
+    pub fn new(group: usize, item: &Item, frequencies: &[usize; QUESTIONS]) -> Self {
+        let answered = frequencies.iter().copied().filter(|&n| n > 0);
+        let (most, least) = (answered.clone().max(), answered.min());
+        let select = |k: Option<usize>| {
+            (0..QUESTIONS)
+                .filter(|&i| Some(frequencies[i]) == k)
+                .map(Answers::question)
+                .collect()
+        };
+        GroupSummary {
+            group,
+            size: item.size(),
+            union: item.union(),
+            intersection: item.intersection(),
+            most_common: select(most),
+            least_common: select(least),
+        }
+    }
+}
+
+impl Report {
+    pub fn new(items: &[Item]) -> Self {
+        let mut groups = Vec::with_capacity(items.len());
+        let mut people = [0; QUESTIONS];
+        for (i, item) in items.iter().enumerate() {
+            let frequencies = item.frequencies();
+            for (total, n) in people.iter_mut().zip(&frequencies) {
+                *total += n;
+            }
+            groups.push(GroupSummary::new(i + 1, item, &frequencies));
+        }
+        let questions = (0..QUESTIONS)
+            .map(|i| {
+                let question = Answers::question(i);
+                QuestionCounts {
+                    question,
+                    people: people[i],
+                    groups: groups.iter().filter(|g| g.union.contains(question)).count(),
+                    unanimous: groups
+                        .iter()
+                        .filter(|g| g.intersection.contains(question))
+                        .count(),
+                }
+            })
+            .collect();
+        Report { groups, questions }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Renders the summaries of the groups as CSV, one row per group.
+    pub fn groups_csv(&self) -> Result<String> {
+        to_csv(&self.groups)
+    }
+
+    /// Renders the counts of the questions as CSV, one row per question.
+    pub fn questions_csv(&self) -> Result<String> {
+        to_csv(&self.questions)
+    }
+}
+
+fn to_csv<T: Serialize>(rows: &[T]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5} {:>4}  {:<26} {:<26} {:<26} least common",
+            "group", "size", "union", "intersection", "most common"
+        )?;
+        for g in &self.groups {
+            writeln!(
+                f,
+                "{:>5} {:>4}  {:<26} {:<26} {:<26} {}",
+                g.group,
+                g.size,
+                g.union.to_string(),
+                g.intersection.to_string(),
+                g.most_common.to_string(),
+                g.least_common
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:>8} {:>6} {:>6} {:>9}",
+            "question", "people", "groups", "unanimous"
+        )?;
+        for q in &self.questions {
+            writeln!(
+                f,
+                "{:>8} {:>6} {:>6} {:>9}",
+                q.question, q.people, q.groups, q.unanimous
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day06;
+
+    #[test]
+    fn test_report() {
+        let items = Day06::parse("abc\nabd\nax\n\nb\nb\n\n").unwrap();
+        let report = Report::new(&items);
+        let group = &report.groups[0];
+        assert_eq!(group.most_common.to_string(), "a");
+        assert_eq!(group.least_common.to_string(), "cdx");
+        assert_eq!(
+            report.questions[1],
+            QuestionCounts {
+                question: 'b',
+                people: 4,
+                groups: 2,
+                unanimous: 1
+            }
+        );
+
+        let csv = report.groups_csv().unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "group,size,union,intersection,most_common,least_common"
+        );
+        assert_eq!(lines.next().unwrap(), "1,3,abcdx,a,a,cdx");
+        assert_eq!(lines.next().unwrap(), "2,2,b,b,b,b");
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["groups"][0]["union"], "abcdx");
+        assert_eq!(json["questions"][0]["people"], 3);
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{ensure, Result};
+use itertools::rev;
+use log::{debug, info, trace};
+use once_cell_regex::regex;
+use petgraph::prelude::*;
+use petgraph::visit::Reversed;
+
+use aoc_common::input::non_empty;
+use aoc_common::{ParseError, Solution};
+
+type Storage = HashMap<(String, String), usize>;
+
+/// Color of our bag.
+const TARGET: &str = "shiny gold";
+
+fn parse_storage(input: &str) -> Result<Storage> {
+    let mut storage: Storage = HashMap::new();
+    let re = regex!(r"^(\w+ \w+) bags? contain (.+)\.$");
+    let re_item = regex!(r"^(\d+) (\w+ \w+) bags?$");
+    for line in input.lines() {
+        let caps = re.captures(line).ok_or_else(|| {
+            ParseError::at(input, line, "Expected `<color> bags contain <contents>.`")
+        })?;
+        let lhs = caps.get(1).unwrap().as_str();
+        let rhs = caps.get(2).unwrap().as_str();
+        if rhs == "no other bags" {
+            continue;
+        }
+        for item in rhs.split(", ") {
+            let caps = re_item
+                .captures(item)
+                .ok_or_else(|| ParseError::at(input, item, "Expected `<count> <color> bags`"))?;
+            let n = caps[1]
+                .parse::<usize>()
+                .map_err(|e| ParseError::at(input, item, format!("Bad count: {}", e)))?;
+            let spec = caps.get(2).unwrap().as_str();
+            let key = (lhs.to_string(), spec.to_string());
+            *storage.entry(key).or_insert(0) += n;
+        }
+    }
+    Ok(storage)
+}
+
+fn build_graph(storage: &Storage) -> DiGraphMap<&str, usize> {
+    let mut graph = DiGraphMap::<&str, usize>::new();
+    for (key, value) in storage.iter() {
+        trace!("{:?}: {}", key, value);
+        let (from, to) = key;
+        if let Some(w) = graph.edge_weight_mut(from, to) {
+            *w += value;
+        } else {
+            graph.add_edge(from, to, *value);
+        }
+    }
+    debug!("Graph: {:?}", graph);
+    trace!("{}", petgraph::dot::Dot::new(&graph));
+
+    assert!(!petgraph::algo::is_cyclic_directed(&graph));
+
+    graph
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Storage;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_storage(non_empty(input)?)
+    }
+
+    fn part_one(storage: &Self::Input) -> Result<Self::AnswerOne> {
+        let graph = build_graph(storage);
+        ensure!(
+            graph.contains_node(TARGET),
+            "No rule mentions `{}` bags",
+            TARGET
+        );
+
+        let reversed = Reversed(&graph);
+        let mut bfs_backward = Bfs::new(&reversed, TARGET);
+        let mut total_backward = 0;
+        while bfs_backward.next(&reversed).is_some() {
+            total_backward += 1;
+        }
+
+        info!(
+            "Total backward-BFS count (excluding start): {}",
+            total_backward - 1
+        );
+        Ok(total_backward - 1)
+    }
+
+    fn part_two(storage: &Self::Input) -> Result<Self::AnswerTwo> {
+        let graph = build_graph(storage);
+        ensure!(
+            graph.contains_node(TARGET),
+            "No rule mentions `{}` bags",
+            TARGET
+        );
+
+        let mut visited = HashSet::new();
+        let mut bfs = Bfs::new(&graph, TARGET);
+        while let Some(v) = bfs.next(&graph) {
+            visited.insert(v);
+        }
+        let topo: Vec<&str> = petgraph::algo::toposort(&graph, None).unwrap();
+        let mut weights = HashMap::new();
+        debug!("Topological sort (reversed):");
+        for v in rev(topo) {
+            if !visited.contains(v) {
+                trace!(" - skipping {}", v);
+                continue;
+            }
+            let w = 1 + graph
+                .neighbors(v)
+                .map(|n| graph.edge_weight(v, n).unwrap() * weights[n])
+                .sum::<usize>();
+            debug!(" - node `{}`: weight = {}", v, w);
+            weights.insert(v, w);
+        }
+        let total_forward = weights[TARGET];
+
+        info!(
+            "Total rev-toposort sum (excluding start): {}",
+            total_forward - 1
+        );
+        Ok(total_forward - 1)
+    }
+}
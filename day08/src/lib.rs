@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use itertools::Either;
+use log::{debug, info, trace};
+use once_cell_regex::regex;
+
+use aoc_common::input::{non_empty, parse_lines};
+use aoc_common::Solution;
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let caps = regex!(r"^(acc|jmp|nop) ([+-]\d+)$")
+            .captures(s)
+            .ok_or_else(|| anyhow!("line does not match"))?;
+
+        use Instruction::*;
+        let arg = caps.get(2).unwrap().as_str().parse()?;
+        Ok(match caps.get(1).unwrap().as_str() {
+            "acc" => Acc(arg),
+            "jmp" => Jmp(arg),
+            "nop" => Nop(arg),
+            _ => unreachable!(),
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    pointer: usize,
+    accumulator: i32,
+}
+
+impl State {
+    fn eval(&mut self, instruction: &Instruction) {
+        match *instruction {
+            Instruction::Acc(arg) => {
+                self.pointer += 1;
+                self.accumulator += arg;
+            }
+            Instruction::Jmp(arg) => {
+                self.pointer = (self.pointer as i32 + arg) as usize;
+            }
+            Instruction::Nop(_) => {
+                self.pointer += 1;
+            }
+        }
+    }
+}
+
+fn find_infinite_loop(program: &[Instruction]) -> Either<Box<State>, Box<State>> {
+    let mut state = State::default();
+    let mut visited = vec![false; program.len()];
+
+    trace!("Executing a program...");
+    loop {
+        visited[state.pointer] = true;
+        let instruction = &program[state.pointer];
+        trace!(" - {:?} ::: {:?}", state, instruction);
+        state.eval(instruction);
+
+        if state.pointer >= program.len() {
+            // No infinite loop found.
+            return Either::Right(Box::new(state));
+        }
+
+        if visited[state.pointer] {
+            // Found an infinite loop.
+            return Either::Left(Box::new(state));
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
+    type AnswerOne = i32;
+    type AnswerTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let program = parse_lines(non_empty(input)?)?;
+
+        debug!("Program:");
+        for instruction in program.iter() {
+            debug!(" - {:?}", instruction);
+        }
+
+        Ok(program)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::AnswerOne> {
+        info!(">>> Searching for infinite loop in the original program...");
+        match find_infinite_loop(program) {
+            Either::Left(state) => {
+                info!("  - Infinite loop found!");
+                info!("  - Last state: {:?}", state);
+                Ok(state.accumulator)
+            }
+            Either::Right(state) => {
+                info!("  - Program ended without going into an infinite loop!");
+                info!("  - Last state: {:?}", state);
+                Err(anyhow!(
+                    "The given program has an infinite loop, but we could not detect it."
+                ))
+            }
+        }
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::AnswerTwo> {
+        info!(">>> Trying to mutate program to make it terminate...");
+        for (i, instruction) in program.iter().enumerate() {
+            use Instruction::*;
+            if let Some(mutated_instruction) = match instruction {
+                Jmp(arg) => Some(Nop(*arg)),
+                Nop(arg) => Some(Jmp(*arg)),
+                Acc(_) => None,
+            } {
+                let mutated_program =
+                    [&program[..i], &[mutated_instruction], &program[i + 1..]].concat();
+                match find_infinite_loop(&mutated_program) {
+                    Either::Left(_) => {}
+                    Either::Right(state) => {
+                        info!("  - Found a mutated program (i = {}) that terminates!", i);
+                        info!("  - Last state: {:?}", state);
+                        return Ok(state.accumulator);
+                    }
+                };
+            }
+        }
+
+        Err(anyhow!("No mutated program terminates"))
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use log::{debug, info, trace};
+
+use aoc_common::input::{non_empty, parse_lines};
+use aoc_common::Solution;
+
+fn check_first(x: u64, data: &[u64]) -> bool {
+    trace!("Checking {} in {:?}", x, data);
+    data.iter().combinations(2).any(|comb| {
+        let a = comb[0];
+        let b = comb[1];
+        a != b && a + b == x
+    })
+}
+
+/// Returns the index and the value of the first number which is not
+/// a sum of two of the 25 numbers before it, if there is one.
+fn find_invalid_number_first(data: &[u64]) -> Option<(usize, u64)> {
+    (25..data.len())
+        .map(|i| (i, data[i]))
+        .find(|&(i, x)| !check_first(x, &data[i - 25..i]))
+}
+
+fn invalid_number(data: &[u64]) -> Result<(usize, u64)> {
+    find_invalid_number_first(data).ok_or_else(|| anyhow!("No invalid number found"))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u64>;
+    type AnswerOne = u64;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(non_empty(input)?)
+    }
+
+    fn part_one(data: &Self::Input) -> Result<Self::AnswerOne> {
+        let (index, value) = invalid_number(data)?;
+        info!("Found invalid number: {}-th = {}", index, value);
+        Ok(value)
+    }
+
+    fn part_two(data: &Self::Input) -> Result<Self::AnswerTwo> {
+        let (_, value) = invalid_number(data)?;
+        for i in 0..data.len() {
+            // Note: the range has at least two numbers and stops at the end of the data
+            let mut j = i;
+            let mut s = data[i];
+            while (s < value || j == i) && j + 1 < data.len() {
+                j += 1;
+                s += data[j];
+            }
+            if s == value && j > i {
+                let range = &data[i..=j];
+                let min = range.iter().min().unwrap();
+                let max = range.iter().max().unwrap();
+                info!(
+                    "Found sum-range for {} of length {} from {}-th ({}) to {}-th ({})",
+                    value,
+                    j - i + 1,
+                    i,
+                    data[i],
+                    j,
+                    data[j]
+                );
+                info!("Sum of min/max = {}+{} = {}", min, max, min + max);
+                debug!("Range: {:?}", range);
+                return Ok(min + max);
+            }
+        }
+
+        Err(anyhow!("No sum-range found for {}", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_found() {
+        let data = (1..=30).collect::<Vec<u64>>();
+        assert!(Day09::part_one(&data).is_err());
+        assert!(Day09::part_two(&data).is_err());
+        let mut data = (1..=25).collect::<Vec<u64>>();
+        data.push(1000);
+        assert_eq!(Day09::part_one(&data).unwrap(), 1000);
+        assert!(Day09::part_two(&data).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use itertools::{rev, Itertools};
+use log::{debug, info};
+use petgraph::algo::toposort;
+use petgraph::prelude::*;
+
+use aoc_common::input::{non_empty, parse_lines};
+use aoc_common::Solution;
+
+fn solve_part_one(data: &[i32]) -> usize {
+    let mut data = data.to_vec();
+    data.push(0);
+    data.sort_unstable();
+    let max = *data.last().unwrap();
+    data.push(max + 3);
+
+    let diffs = data.windows(2).map(|w| w[1] - w[0]).collect_vec();
+    debug!("Sequence: {:?}", data);
+    debug!("Diffs: {:?}", diffs);
+    let diffs1 = diffs.iter().filter(|&&x| x == 1).count();
+    let diffs3 = diffs.iter().filter(|&&x| x == 3).count();
+    info!("1-diffs: {}", diffs1);
+    info!("3-diffs: {}", diffs3);
+    info!("[part1] 1-diffs * 3-diffs = {}", diffs1 * diffs3);
+    diffs1 * diffs3
+}
+
+fn solve_part_two(data: &[i32]) -> u64 {
+    let mut data = data.to_vec();
+    let max = *data.iter().max().unwrap();
+    let end = max + 3;
+    data.push(0);
+    data.push(end);
+    data.sort_unstable();
+
+    assert_eq!(data.iter().unique().count(), data.len());
+
+    let mut graph = DiGraphMap::new();
+    for comb in data.iter().combinations(2) {
+        let a = *comb[0];
+        let b = *comb[1];
+        if (0..=3).contains(&(b - a)) {
+            graph.add_edge(a, b, ());
+        }
+    }
+
+    let topo = toposort(&graph, None).unwrap();
+    let mut weights = HashMap::<_, u64>::new();
+    weights.insert(end, 1);
+    for v in rev(topo).skip(1) {
+        let w = graph.neighbors(v).map(|u| weights[&u]).sum();
+        debug!("Weight of {} is {}", v, w);
+        weights.insert(v, w);
+    }
+    info!("[part2] paths: {}", weights[&0]);
+    weights[&0]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<i32>;
+    type AnswerOne = usize;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(non_empty(input)?)
+    }
+
+    fn part_one(data: &Self::Input) -> Result<Self::AnswerOne> {
+        Ok(solve_part_one(data))
+    }
+
+    fn part_two(data: &Self::Input) -> Result<Self::AnswerTwo> {
+        Ok(solve_part_two(data))
+    }
+}
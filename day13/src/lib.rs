@@ -0,0 +1,177 @@
+use std::iter::Sum;
+use std::ops::Rem;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use itertools::Itertools;
+use log::{debug, info};
+use num_integer::{ExtendedGcd, Integer};
+
+use aoc_common::input::parse_csv;
+use aoc_common::Solution;
+
+#[derive(Debug)]
+pub struct Schedule {
+    ids: Vec<Option<u64>>,
+}
+
+/// Schedule entry: either a bus id or `x` for a bus that is out of service.
+struct Bus(Option<u64>);
+
+impl FromStr for Bus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "x" => Ok(Bus(None)),
+            _ => Ok(Bus(Some(s.parse()?))),
+        }
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ids = parse_csv::<Bus>(s)?.into_iter().map(|bus| bus.0).collect();
+        Ok(Schedule { ids })
+    }
+}
+
+trait SumRem<T = Self>
+where
+    T: Rem<Output = T>,
+{
+    fn sum_rem<I>(iter: I, modulo: T) -> Self
+    where
+        I: Iterator<Item = T>;
+}
+
+impl<T> SumRem for T
+where
+    T: Copy + Sum + Rem<Output = T>,
+{
+    fn sum_rem<I>(iter: I, modulo: T) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.map(|x| x % modulo).sum::<T>() % modulo
+    }
+}
+
+trait IteratorExt: Iterator {
+    fn sum_rem<S>(self, modulo: Self::Item) -> S
+    where
+        Self: Sized,
+        S: SumRem<Self::Item>,
+        Self::Item: Rem<Output = Self::Item>,
+    {
+        SumRem::sum_rem(self, modulo)
+    }
+}
+
+impl<I> IteratorExt for I where I: Iterator {}
+
+fn modulus_inverse(x: i64, y: i64) -> Option<u64> {
+    // solution for `a*x + b*y = gcd(x,y)` is egcd = (gcd, a, b)
+    let ExtendedGcd {
+        gcd, x: a, y: _b, ..
+    } = Integer::extended_gcd(&x, &y);
+
+    if gcd == 1 {
+        let m = mod_fix(a, y);
+        debug!(
+            "egcd({}, {}) = ({}, {}, {}), mod-inv is {}",
+            x, y, gcd, a, _b, m
+        );
+        Some(m)
+    } else {
+        None
+    }
+}
+
+fn mod_fix(x: i64, m: i64) -> u64 {
+    ((x % m + m) % m) as u64
+}
+
+fn solve_part_one(schedule: &Schedule, start: i32) -> i32 {
+    let ids = schedule.ids.iter().filter_map(|&x| x).collect_vec();
+    let (nearest_time, nearest_id) = ids
+        .iter()
+        .map(|&t| {
+            (
+                ((start as f32 / t as f32).ceil() * t as f32) as i32,
+                t as i32,
+            )
+        })
+        .min()
+        .unwrap();
+    let diff = nearest_time - start;
+    info!(
+        "Nearest bus is {} at {}, which is {} minutes away from the start ({})",
+        nearest_id, nearest_time, diff, start
+    );
+    info!("product = {}", nearest_id * diff);
+    nearest_id * diff
+}
+
+fn solve_part_two(schedule: &Schedule) -> u64 {
+    let data = schedule
+        .ids
+        .iter()
+        .enumerate()
+        .filter_map(|(i, id)| id.map(|a| (i, a)))
+        .collect_vec();
+    let modulii = data.iter().map(|&(_, x)| x).collect_vec();
+    debug!("modulii = {:?}", modulii);
+    let product: u64 = modulii.iter().product();
+    debug!("product = {}", product);
+    let residues = data
+        .iter()
+        .map(|&(i, x)| mod_fix(x as i64 - i as i64, x as i64))
+        .collect_vec();
+    debug!("residues = {:?}", residues);
+    let ms = modulii.iter().map(|&p| product / p).collect_vec();
+    debug!("ms = {:?}", ms);
+    let t: u64 = (0..data.len())
+        .map(|i| {
+            let modulus = modulii[i];
+            let residue = residues[i];
+            let m = product / modulus;
+            residue * m * modulus_inverse(m as i64, modulus as i64).unwrap()
+        })
+        .sum_rem(product);
+    info!("t = {}", t);
+    t
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (i32, Schedule);
+    type AnswerOne = i32;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = input.lines();
+        let start = lines
+            .next()
+            .ok_or_else(|| anyhow!("Missing start time"))?
+            .parse::<i32>()?;
+        let schedule = lines
+            .next()
+            .ok_or_else(|| anyhow!("Missing schedule"))?
+            .parse::<Schedule>()?;
+        Ok((start, schedule))
+    }
+
+    fn part_one((start, schedule): &Self::Input) -> Result<Self::AnswerOne> {
+        info!("Solving part 1...");
+        Ok(solve_part_one(schedule, *start))
+    }
+
+    fn part_two((_, schedule): &Self::Input) -> Result<Self::AnswerTwo> {
+        info!("Solving part 2...");
+        Ok(solve_part_two(schedule))
+    }
+}
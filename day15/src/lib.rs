@@ -0,0 +1,183 @@
+use anyhow::{anyhow, Result};
+use log::{debug, info};
+use vec_map::VecMap;
+
+use aoc_common::input::parse_csv;
+use aoc_common::Solution;
+
+#[derive(Debug)]
+struct SpokenNumbers {
+    storage: VecMap<usize>,
+    step: usize,
+    last: usize,
+}
+
+impl SpokenNumbers {
+    fn with_capacity(data: &[usize], capacity: usize) -> Self {
+        assert!(!data.is_empty());
+        let mut storage = VecMap::with_capacity(capacity.max(data.len()));
+        let mut last = 0;
+
+        for (i, &x) in data.iter().enumerate() {
+            if i > 0 {
+                storage.insert(last, i - 1);
+            }
+            last = x;
+        }
+
+        SpokenNumbers {
+            storage,
+            step: data.len(),
+            last,
+        }
+    }
+
+    fn push(&mut self, value: usize) {
+        self.storage.insert(self.last, self.step - 1);
+        self.last = value;
+        self.step += 1;
+    }
+}
+
+impl Iterator for SpokenNumbers {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        assert!(self.step > 0);
+
+        let age = if let Some(index) = self.storage.get(self.last) {
+            self.step - 1 - index
+        } else {
+            0
+        };
+        self.push(age);
+
+        Some(self.last)
+    }
+}
+
+fn spoken_number(data: &[usize], n: usize) -> usize {
+    // Note: `n` is 1-based
+    assert!(!data.is_empty());
+    assert!(n > data.len());
+
+    let mut s = SpokenNumbers::with_capacity(data, n);
+    s.nth(n - 1 - data.len()).unwrap()
+}
+
+fn solve(data: &[usize], steps: usize) -> usize {
+    let last = spoken_number(data, steps);
+    info!(
+        "Last spoken number for {:?} after {} steps is {}",
+        data, steps, last
+    );
+    last
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let data = parse_csv(input.lines().next().ok_or_else(|| anyhow!("Empty input"))?)?;
+        debug!("{:?}", data);
+        Ok(data)
+    }
+
+    fn part_one(data: &Self::Input) -> Result<Self::AnswerOne> {
+        info!("Solving part 1...");
+        Ok(solve(data, 2020))
+    }
+
+    fn part_two(data: &Self::Input) -> Result<Self::AnswerTwo> {
+        info!("Solving part 2...");
+        Ok(solve(data, 30_000_000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_036() {
+        let data = vec![0, 3, 6];
+        let res10 = spoken_number(&data, 10);
+        assert_eq!(res10, 0);
+        let res2020 = spoken_number(&data, 2020);
+        assert_eq!(res2020, 436);
+    }
+
+    #[test]
+    fn test_sample_132() {
+        let data = vec![1, 3, 2]; // 1
+        let res = spoken_number(&data, 2020);
+        assert_eq!(res, 1);
+    }
+
+    #[test]
+    fn test_sample_213() {
+        let data = vec![2, 1, 3]; // 10
+        let res = spoken_number(&data, 2020);
+        assert_eq!(res, 10);
+    }
+
+    #[test]
+    fn test_sample_123() {
+        let data = vec![1, 2, 3]; // 27
+        let res = spoken_number(&data, 2020);
+        assert_eq!(res, 27);
+    }
+
+    #[test]
+    fn test_sample_231() {
+        let data = vec![2, 3, 1]; // 78
+        let res = spoken_number(&data, 2020);
+        assert_eq!(res, 78);
+    }
+
+    #[test]
+    fn test_sample_321() {
+        let data = vec![3, 2, 1]; // 438
+        let res = spoken_number(&data, 2020);
+        assert_eq!(res, 438);
+    }
+
+    #[test]
+    fn test_sample_312() {
+        let data = vec![3, 1, 2]; // 1836
+        let res = spoken_number(&data, 2020);
+        assert_eq!(res, 1836);
+    }
+
+    #[test]
+    fn test_my_2020() {
+        let data = vec![2, 20, 0, 4, 1, 17];
+        let res = spoken_number(&data, 2020);
+        assert_eq!(res, 758);
+    }
+
+    #[test]
+    fn test_my_30kk() {
+        let data = vec![2, 20, 0, 4, 1, 17];
+        let res = spoken_number(&data, 30_000_000);
+        assert_eq!(res, 814);
+    }
+
+    #[test]
+    fn test_danvk_2020() {
+        let data = vec![0, 20, 7, 16, 1, 18, 15];
+        let res = spoken_number(&data, 2020);
+        assert_eq!(res, 1025);
+    }
+
+    #[test]
+    fn test_danvk_30kk() {
+        let data = vec![0, 20, 7, 16, 1, 18, 15];
+        let res = spoken_number(&data, 30_000_000);
+        assert_eq!(res, 129262);
+    }
+}
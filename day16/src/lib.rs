@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+use std::iter::once;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use itertools::Itertools;
+use log::{debug, info};
+use once_cell_regex::regex;
+
+use aoc_common::input::{parse_csv, parse_lines, sections};
+use aoc_common::{ParseError, Solution};
+
+#[derive(Debug, Hash, Eq, PartialEq)]
+pub struct Rule {
+    field: String,
+    ranges: (RangeInclusive<u32>, RangeInclusive<u32>),
+}
+
+impl Rule {
+    fn contains(&self, value: &u32) -> bool {
+        let (r1, r2) = &self.ranges;
+        r1.contains(value) || r2.contains(value)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = regex!(r"^([\w\s]+): (\d+)-(\d+) or (\d+)-(\d+)$");
+        if let Some(caps) = re.captures(s) {
+            let field = caps.get(1).unwrap().as_str().to_string();
+            let min1 = caps.get(2).unwrap().as_str().parse()?;
+            let max1 = caps.get(3).unwrap().as_str().parse()?;
+            let min2 = caps.get(4).unwrap().as_str().parse()?;
+            let max2 = caps.get(5).unwrap().as_str().parse()?;
+            Ok(Rule {
+                field,
+                ranges: (min1..=max1, min2..=max2),
+            })
+        } else {
+            Err(anyhow!("No match for `{}`", s))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Ticket {
+    numbers: Vec<u32>,
+}
+
+impl FromStr for Ticket {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = parse_csv(s)?;
+        Ok(Ticket { numbers })
+    }
+}
+
+fn solve_part_one(rules: &[Rule], nearby_tickets: &[Ticket]) -> u32 {
+    let ranges = rules
+        .iter()
+        .flat_map(|r| once(&r.ranges.0).chain(once(&r.ranges.1)))
+        .collect_vec();
+    debug!("All ranges: {:?}", ranges);
+    let rate: u32 = nearby_tickets
+        .iter()
+        .flat_map(|t| t.numbers.clone())
+        .filter(|x| ranges.iter().all(|r| !r.contains(x)))
+        .sum();
+    info!("Ticket scanning error rate: {}", rate);
+    rate
+}
+
+fn find_field_values<'a>(
+    rules: &'a [Rule],
+    tickets: &[&Ticket],
+    values: &[u32],
+) -> HashMap<&'a Rule, u32> {
+    let mut field_value = HashMap::new();
+    let mut used_positions = HashSet::new();
+    // Note: one can omit the variable `used_positions` and replace the check
+    //  `used_positions.contains(&i)` with `field_value.values().any(|&j| j == i)`.
+    // Here, simpler solution with extra HashSet variable was chosen.
+
+    while field_value.len() < rules.len() {
+        let mut found_any = false;
+        for rule in rules.iter() {
+            // Skip rules with already inferred positions
+            if field_value.contains_key(rule) {
+                continue;
+            }
+            // Find possible positions for the field `rule.field`
+            let possible = values
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| {
+                    tickets
+                        .iter()
+                        .all(|t| rule.contains(&t.numbers[i]) && !used_positions.contains(&i))
+                })
+                .collect_vec();
+            // If the possible position is the only one, then save and use it
+            if possible.len() == 1 {
+                let (position, &value) = possible[0];
+                field_value.insert(rule, value);
+                used_positions.insert(position);
+                found_any = true;
+            }
+        }
+        // Note: in general, this problem can have either many or no solutions,
+        //  but we are given the inputs for which the problem is guaranteed
+        //  to have only one solution, which can be found iteratively by the presented loop.
+        // If only something gone wrong, this check just aborts the infinite loop;
+        //  in this situation, check your inputs!
+        if !found_any {
+            unreachable!("Stale");
+        }
+    }
+
+    field_value
+}
+
+fn solve_part_two(rules: &[Rule], my_ticket: &Ticket, nearby_tickets: &[Ticket]) -> u64 {
+    let valid_tickets = nearby_tickets
+        .iter()
+        .filter(|t| {
+            t.numbers
+                .iter()
+                .all(|x| rules.iter().any(|r| r.contains(x)))
+        })
+        .chain(once(my_ticket))
+        .collect_vec();
+
+    let field_value = find_field_values(rules, &valid_tickets, &my_ticket.numbers);
+
+    let product: u64 = rules
+        .iter()
+        .filter(|r| r.field.starts_with("departure"))
+        .map(|r| field_value[r] as u64)
+        .product();
+    info!("Product of values for `departure`-fields: {}", product);
+    product
+}
+
+/// Strips the `header` line from the `section` of the `input`.
+fn strip_header<'a>(input: &str, section: &'a str, header: &str) -> Result<&'a str, ParseError> {
+    match section.split_once('\n') {
+        Some((first, rest)) if first.trim_end() == header => Ok(rest),
+        _ => Err(ParseError::at(
+            input,
+            section,
+            format!("Expected section `{}`", header),
+        )),
+    }
+}
+
+fn parse_notes(input: &str) -> Result<(Vec<Rule>, Ticket, Vec<Ticket>)> {
+    let (rules, my_ticket, nearby_tickets) = match sections(input)[..] {
+        [rules, my_ticket, nearby_tickets] => (rules, my_ticket, nearby_tickets),
+        ref s => return Err(anyhow!("Expected 3 sections, found {}", s.len())),
+    };
+    let rules = parse_lines(rules).map_err(|e| ParseError::locate(e, input, rules))?;
+    let my_ticket = strip_header(input, my_ticket, "your ticket:")?;
+    let my_ticket = my_ticket
+        .parse()
+        .map_err(|e| ParseError::locate(e, input, my_ticket))?;
+    let nearby_tickets = strip_header(input, nearby_tickets, "nearby tickets:")?;
+    let nearby_tickets =
+        parse_lines(nearby_tickets).map_err(|e| ParseError::locate(e, input, nearby_tickets))?;
+    Ok((rules, my_ticket, nearby_tickets))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = (Vec<Rule>, Ticket, Vec<Ticket>);
+    type AnswerOne = u32;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, my_ticket, nearby_tickets) = parse_notes(input)?;
+
+        debug!("rules: {:?}", rules);
+        debug!("my ticket: {:?}", my_ticket);
+        debug!("nearby tickets: {:?}", nearby_tickets);
+
+        Ok((rules, my_ticket, nearby_tickets))
+    }
+
+    fn part_one((rules, _my_ticket, nearby_tickets): &Self::Input) -> Result<Self::AnswerOne> {
+        info!("Solving part 1...");
+        Ok(solve_part_one(rules, nearby_tickets))
+    }
+
+    fn part_two((rules, my_ticket, nearby_tickets): &Self::Input) -> Result<Self::AnswerTwo> {
+        info!("Solving part 2...");
+        Ok(solve_part_two(rules, my_ticket, nearby_tickets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one_sample1() {
+        let input = fs::read_to_string("data/sample1.txt").unwrap();
+        let (rules, _my_ticket, nearby_tickets) = Day16::parse(&input).unwrap();
+        let result = solve_part_one(&rules, &nearby_tickets);
+        assert_eq!(result, 71);
+    }
+
+    #[test]
+    fn test_part_one_input() {
+        let input = fs::read_to_string("data/input.txt").unwrap();
+        let (rules, _my_ticket, nearby_tickets) = Day16::parse(&input).unwrap();
+        let result = solve_part_one(&rules, &nearby_tickets);
+        assert_eq!(result, 21980);
+    }
+
+    #[test]
+    fn test_part_two_input() {
+        let input = fs::read_to_string("data/input.txt").unwrap();
+        let (rules, my_ticket, nearby_tickets) = Day16::parse(&input).unwrap();
+        let result = solve_part_two(&rules, &my_ticket, &nearby_tickets);
+        assert_eq!(result, 1439429522627);
+    }
+}
@@ -0,0 +1,250 @@
+#[macro_use]
+extern crate derive_new;
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::iter::once;
+use std::ops::Add;
+
+use anyhow::{anyhow, Error, Result};
+use itertools::{sorted, Itertools};
+use log::{debug, info, log, log_enabled, trace, Level};
+use once_cell::sync::Lazy;
+use pipe_trait::Pipe;
+
+use aoc_common::input::parse_lines_with;
+use aoc_common::{ParseError, Solution};
+
+// Axes:
+//   |
+// --+---> (y)
+//   |
+//   v (x)
+
+#[derive(Debug, Copy, Clone)]
+pub enum State {
+    Empty,
+    Active,
+}
+
+impl TryFrom<char> for State {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        use State::*;
+        match c {
+            '.' => Ok(Empty),
+            '#' => Ok(Active),
+            _ => Err(anyhow!("Bad char `{}`", c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, new)]
+struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+    w: i32,
+}
+
+impl Point {
+    fn neighbors<'a>(&'a self, ds: &'a [Delta]) -> impl Iterator<Item = Point> + 'a {
+        ds.iter().map(move |&d| self + d)
+    }
+}
+
+type Delta = (i32, i32, i32, i32);
+
+impl Add<(i32, i32, i32, i32)> for &Point {
+    type Output = Point;
+
+    fn add(self, rhs: (i32, i32, i32, i32)) -> Self::Output {
+        Point {
+            x: self.x + rhs.0,
+            y: self.y + rhs.1,
+            z: self.z + rhs.2,
+            w: self.w + rhs.3,
+        }
+    }
+}
+
+/// Neighborhood deltas in 4 dimensions.
+static DS: Lazy<Vec<Delta>> = Lazy::new(|| {
+    // let ds = itertools::iproduct!(-1..=1, -1..=1, -1..=1, -1..=1)
+    //     .filter(|&(x,y,z,w)| !(x == 0 && y == 0 && z == 0 && w == 0))
+    //     .collect_vec();
+    let mut ds = Vec::new();
+    for x in -1..=1 {
+        for y in -1..=1 {
+            for z in -1..=1 {
+                for w in -1..=1 {
+                    if !(x == 0 && y == 0 && z == 0 && w == 0) {
+                        ds.push((x, y, z, w));
+                    }
+                }
+            }
+        }
+    }
+    assert_eq!(80, ds.len());
+    ds
+});
+
+/// Neighborhood deltas in 3 dimensions, i.e. with `w = 0`.
+static DS3: Lazy<Vec<Delta>> = Lazy::new(|| {
+    let ds = DS
+        .iter()
+        .copied()
+        .filter(|&(_, _, _, w)| w == 0)
+        .collect_vec();
+    assert_eq!(26, ds.len());
+    ds
+});
+
+fn conway_cubes_step(data: &HashSet<Point>, ds: &[Delta]) -> HashSet<Point> {
+    let is_active = |p: &Point| -> bool { data.contains(p) };
+    data.iter()
+        // Note: `Point::neighbors` returns an iterator over neighbors,
+        //  not including the point itself. Here, we need to also consider
+        //  the point `p` itself, so we `chain` it to the resulting iterator.
+        .flat_map(|p| p.neighbors(ds).chain(once(p.clone())))
+        .filter(|p| {
+            let active_neighbors = p.neighbors(ds).filter(is_active).count();
+            trace!("{:?} has {} active neighbor(s)", p, active_neighbors);
+            if is_active(p) {
+                active_neighbors == 2 || active_neighbors == 3
+            } else {
+                active_neighbors == 3
+            }
+        })
+        .collect()
+}
+
+fn log_active(level: Level, data: &HashSet<Point>) {
+    if !log_enabled!(level) {
+        return;
+    }
+    use itertools::MinMaxResult::{MinMax, NoElements, OneElement};
+    let (x_min, x_max) = {
+        let x_minmax = data.iter().map(|p| p.x).minmax();
+        match x_minmax {
+            NoElements => panic!("No xs"),
+            OneElement(t) => (t, t),
+            MinMax(min, max) => (min, max),
+        }
+    };
+    let (y_min, y_max) = {
+        let y_minmax = data.iter().map(|p| p.y).minmax();
+        match y_minmax {
+            NoElements => panic!("No ys"),
+            OneElement(t) => (t, t),
+            MinMax(min, max) => (min, max),
+        }
+    };
+
+    for z in data.iter().map(|p| p.z).unique().pipe(sorted) {
+        for w in data.iter().map(|p| p.w).unique().pipe(sorted) {
+            log!(
+                level,
+                "z = {}, w = {}  (x: [{}..{}], y: [{}..{}])",
+                z,
+                w,
+                x_min,
+                x_max,
+                y_min,
+                y_max
+            );
+            for x in x_min..=x_max {
+                let s = (y_min..=y_max)
+                    .map(|y| {
+                        if data.contains(&Point::new(x, y, z, w)) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>();
+                log!(level, "{}", s);
+            }
+        }
+    }
+}
+
+fn solve(data: &[Vec<State>], ds: &[Delta]) -> usize {
+    let mut active = HashSet::<Point>::new();
+    for (x, row) in data.iter().enumerate() {
+        for (y, state) in row.iter().enumerate() {
+            if matches!(state, State::Active) {
+                active.insert(Point::new(x as i32, y as i32, 0, 0));
+            }
+        }
+    }
+    debug!("Initial active cubes:");
+    log_active(Level::Debug, &active);
+
+    let n = 6;
+    for i in 0..n {
+        info!("Cycle {}...", i + 1);
+        active = conway_cubes_step(&active, ds);
+        trace!("Active cubes after {} cycle(s):", i + 1);
+        log_active(Level::Trace, &active);
+    }
+
+    info!(
+        "Number of active cubes after {} cycles: {}",
+        n,
+        active.len()
+    );
+    active.len()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Vec<State>>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines_with(input, |line| {
+            line.char_indices()
+                .map(|(i, c)| {
+                    State::try_from(c).map_err(|e| ParseError::at(line, &line[i..], e.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+    }
+
+    fn part_one(data: &Self::Input) -> Result<Self::AnswerOne> {
+        info!("Solving part 1...");
+        Ok(solve(data, &DS3))
+    }
+
+    fn part_two(data: &Self::Input) -> Result<Self::AnswerTwo> {
+        info!("Solving part 2...");
+        Ok(solve(data, &DS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_sample_part_one() {
+        let input = fs::read_to_string("data/sample.txt").unwrap();
+        let data = Day17::parse(&input).unwrap();
+        let result = solve(&data, &DS3);
+        assert_eq!(result, 112);
+    }
+
+    #[test]
+    fn test_sample() {
+        let input = fs::read_to_string("data/sample.txt").unwrap();
+        let data = Day17::parse(&input).unwrap();
+        let result = solve(&data, &DS);
+        assert_eq!(result, 848);
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use itertools::Itertools;
+use log::{debug, info};
+
+use aoc_common::input::parse_lines_with;
+use aoc_common::Solution;
+
+mod parser_rev;
+mod parser_same;
+
+fn solve_part_one(data: &[String]) -> Result<i64> {
+    let parsed: Vec<i64> = data
+        .iter()
+        .map(|line| parser_same::parse(line))
+        .try_collect()?;
+    debug!("Parsed values: {:?}", parsed);
+    let sum = parsed.iter().sum();
+    info!("Sum: {}", sum);
+    Ok(sum)
+}
+
+fn solve_part_two(data: &[String]) -> Result<i64> {
+    let parsed: Vec<i64> = data
+        .iter()
+        .map(|line| parser_rev::parse(line))
+        .try_collect()?;
+    debug!("Parsed values: {:?}", parsed);
+    let sum = parsed.iter().sum();
+    info!("Sum: {}", sum);
+    Ok(sum)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<String>;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // Note: both grammars share the syntax, so checking one of them is enough
+        parse_lines_with(input, |line| {
+            parser_same::parse(line).map(|_| line.to_string())
+        })
+    }
+
+    fn part_one(data: &Self::Input) -> Result<Self::AnswerOne> {
+        info!("Solving part 1...");
+        solve_part_one(data)
+    }
+
+    fn part_two(data: &Self::Input) -> Result<Self::AnswerTwo> {
+        info!("Solving part 2...");
+        solve_part_two(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = fs::read_to_string("data/input.txt").unwrap();
+        let data = Day18::parse(&input).unwrap();
+        let result = solve_part_one(&data).unwrap();
+        assert_eq!(98621258158412, result);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = fs::read_to_string("data/input.txt").unwrap();
+        let data = Day18::parse(&input).unwrap();
+        let result = solve_part_two(&data).unwrap();
+        assert_eq!(241216538527890, result);
+    }
+}
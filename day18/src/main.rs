@@ -1,75 +1,19 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
-
 use anyhow::Result;
-use itertools::Itertools;
-
-mod parser_rev;
-mod parser_same;
-
-fn solve_part_one(data: &[String]) -> i64 {
-    let parsed = data
-        .iter()
-        .map(|line| parser_same::parse(line))
-        .collect_vec();
-    // println!("Parsed values: {:?}", parsed);
-    let sum = parsed.iter().sum();
-    println!("Sum: {}", sum);
-    sum
-}
 
-fn solve_part_two(data: &[String]) -> i64 {
-    let parsed = data
-        .iter()
-        .map(|line| parser_rev::parse(line))
-        .collect_vec();
-    // println!("Parsed values: {:?}", parsed);
-    let sum = parsed.iter().sum();
-    println!("Sum: {}", sum);
-    sum
-}
-
-fn read_data(path: &str) -> Result<Vec<String>> {
-    let data = BufReader::new(File::open(path)?).lines().try_collect()?;
-    Ok(data)
-}
+use aoc_common::input::read_input;
+use aoc_common::{logging, with_file, Solution};
+use day18::Day18;
 
 fn main() -> Result<()> {
-    let start_time = Instant::now();
+    logging::init_from_env()?;
+
     let path = "data/input.txt";
     // let path = "data/sample.txt"; // 71
-    let data = read_data(path)?;
-
-    println!("Solving part 1...");
-    solve_part_one(&data);
-    println!();
+    let input = read_input(path)?;
+    let data = Day18::parse(&input).map_err(|e| with_file(e, path))?;
 
-    println!("Solving part 2...");
-    solve_part_two(&data);
-    println!();
+    println!("[part1] {}", Day18::part_one(&data)?);
+    println!("[part2] {}", Day18::part_two(&data)?);
 
-    println!("All done in {:.3} s", start_time.elapsed().as_secs_f32());
     Ok(())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let path = "data/input.txt";
-        let data = read_data(path).unwrap();
-        let result = solve_part_one(&data);
-        assert_eq!(98621258158412, result);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let path = "data/input.txt";
-        let data = read_data(path).unwrap();
-        let result = solve_part_two(&data);
-        assert_eq!(241216538527890, result);
-    }
-}
@@ -6,30 +6,35 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{char, digit1, multispace0};
-use nom::combinator::map_res;
+use nom::combinator::{cut, map_res};
+use nom::error::ErrorKind;
 use nom::multi::fold_many0;
-use nom::sequence::{delimited, pair};
+use nom::sequence::{delimited, pair, preceded, terminated};
 use nom::Finish;
 
+use aoc_common::ParseError;
+
 type Parsed<'a, T> = nom::IResult<&'a str, T>;
 
-fn parens(i: &str) -> Parsed<i64> {
-    delimited(tag("("), expr, tag(")"))(i)
+/// Parses a parenthesized expression; once `(` is seen, any error is final,
+/// so that a missing `)` is reported where it is expected.
+fn parens(i: &str) -> Parsed<'_, i64> {
+    preceded(tag("("), cut(terminated(expr, char(')'))))(i)
 }
 
-fn number(i: &str) -> Parsed<i64> {
+fn number(i: &str) -> Parsed<'_, i64> {
     map_res(digit1, |s: &str| s.parse())(i)
 }
 
-fn primary(i: &str) -> Parsed<i64> {
+fn primary(i: &str) -> Parsed<'_, i64> {
     delimited(multispace0, alt((number, parens)), multispace0)(i)
 }
 
-fn term(i: &str) -> Parsed<i64> {
+fn term(i: &str) -> Parsed<'_, i64> {
     primary(i)
 }
 
-fn expr(i: &str) -> Parsed<i64> {
+fn expr(i: &str) -> Parsed<'_, i64> {
     let (i, init) = term(i)?;
     fold_many0(
         pair(alt((char('+'), char('-'), char('*'), char('/'))), term),
@@ -44,10 +49,18 @@ fn expr(i: &str) -> Parsed<i64> {
     )(i)
 }
 
-pub(crate) fn parse(line: &str) -> i64 {
-    let (unparsed, value) = expr(line).finish().unwrap();
-    assert!(unparsed.is_empty());
-    value
+pub(crate) fn parse(line: &str) -> Result<i64, ParseError> {
+    match expr(line).finish() {
+        Ok(("", value)) => Ok(value),
+        Ok((unparsed, _)) => Err(ParseError::at(line, unparsed, "Unexpected input")),
+        // Note: the closing paren is the only `char` which fails hard
+        Err(e) if e.code == ErrorKind::Char => Err(ParseError::at(line, e.input, "Missing `)`")),
+        Err(e) => Err(ParseError::at(
+            line,
+            e.input,
+            format!("Syntax error: expected {}", e.code.description()),
+        )),
+    }
 }
 
 #[cfg(test)]
@@ -57,21 +70,31 @@ mod tests {
     #[test]
     fn test_samples() {
         let input = "1 + 2 * 3";
-        assert_eq!(9, parse(input));
+        assert_eq!(9, parse(input).unwrap());
 
         let input = "1 + 2 * 3 + 4 * 5 + 6";
-        assert_eq!(71, parse(input));
+        assert_eq!(71, parse(input).unwrap());
 
         let input = "2 * 3 + (4 * 5)";
-        assert_eq!(26, parse(input));
+        assert_eq!(26, parse(input).unwrap());
 
         let input = "5 + (8 * 3 + 9 + 3 * 4 * 3)";
-        assert_eq!(437, parse(input));
+        assert_eq!(437, parse(input).unwrap());
 
         let input = "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))";
-        assert_eq!(12240, parse(input));
+        assert_eq!(12240, parse(input).unwrap());
 
         let input = "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
-        assert_eq!(13632, parse(input));
+        assert_eq!(13632, parse(input).unwrap());
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let e = parse("1 + (2 * 3").unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (11, "Missing `)`"));
+        let e = parse("((1 + 2) * 3").unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (13, "Missing `)`"));
+        let e = parse("1 + 2 $ 3").unwrap_err();
+        assert_eq!(e.column, 7);
     }
 }
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use log::{debug, info, log_enabled, trace, Level};
+use regex::Regex;
+use tap::Pipe;
+
+use aoc_common::input::{parse_lines, sections};
+use aoc_common::{ParseError, Solution};
+
+use crate::rule::RuleLine;
+
+mod parser;
+mod rule;
+
+fn solve_part_one(rules: &[RuleLine], messages: &[String]) -> usize {
+    let rulemap: HashMap<_, _> = rules.iter().map(|r| (r.index, &r.rule)).collect();
+    let re_str = rulemap[&0]
+        .to_regex_string(&rulemap)
+        .pipe(|s| format!(r"^{}$", s));
+    debug!("Regex: {:?}", re_str);
+    let re = Regex::new(&re_str).unwrap();
+
+    let count = messages.iter().filter(|m| re.is_match(m)).count();
+    info!("Total matching messages: {}", count);
+    count
+}
+
+fn solve_part_two(rules: &[RuleLine], messages: &[String]) -> usize {
+    let rulemap: HashMap<_, _> = rules.iter().map(|r| (r.index, &r.rule)).collect();
+
+    let re_str = {
+        let mut cache = HashMap::new();
+        let rule31 = &rulemap[&31];
+        let rule31_re_str = rule31.to_regex_string_with_cache(&rulemap, &mut cache);
+        cache.insert(31, rule31_re_str.clone());
+        let rule42 = &rulemap[&42];
+        let rule42_re_str = rule42.to_regex_string_with_cache(&rulemap, &mut cache);
+        cache.insert(42, rule42_re_str.clone());
+
+        // 8 ::= 42 | 42 8
+        // This is just a regular expression r"(42)+"
+        let new_rule8_re_str = format!("({})+", rule42_re_str);
+        cache.insert(8, new_rule8_re_str);
+
+        // 11 ::= 42 31 | 42 11 31
+        // This cannot be represented by a regular expression,
+        //  because we need something like r"42{i} 11* 31{j}", where `i=j`,
+        //  but regular expressions do not have "memory", so we can't express `i=j`.
+        // However, here I use dirty hack by encoding the necessary "non-regular expression"
+        //  as "regular" alternation of the following form:
+        //    r"42 31 | 42 42 31 31 | ... | 42{20} 31{20}",
+        //  where both 42 and 31 are repeated the same number of times.
+        // Note that the upper bound "20" is purely heuristic and seems to "just work".
+        // If it doesn't, try to increase it, but keep in mind that the resulting regex
+        //  could not be compilable due to high memory usage.
+        // For example, using "50" as an upper bound, panics with CompiledTooBig error.
+        // In order to reduce the memory usage, non-capturing groups (namely, r"(?:)")
+        //  were used, but anyway, compiled regexes end up being very large...
+        // Also note that here we pre-fill the cache and heavily rely on the fact
+        //  (given in the problem statement) that rules are acyclic.
+        let new_rule11_re_str = (1..=20)
+            .map(|i| {
+                format!(
+                    "(?:{}){{{}}}(?:{}){{{}}}",
+                    rule42_re_str, i, rule31_re_str, i
+                )
+            })
+            .join("|")
+            .pipe(|s| format!("(?:{})", s));
+        trace!("new_rule11_re_str = {}", new_rule11_re_str);
+        cache.insert(11, new_rule11_re_str);
+
+        rulemap[&0]
+            .to_regex_string_with_cache(&rulemap, &mut cache)
+            .pipe(|s| format!(r"^{}$", s))
+    };
+    debug!("Regex: {:?}", re_str);
+    let re = Regex::new(&re_str).unwrap();
+
+    let count = messages.iter().filter(|m| re.is_match(m)).count();
+    info!("Total matching messages: {}", count);
+    count
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<RuleLine>, Vec<String>);
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, messages) = match sections(input)[..] {
+            [rules, messages] => (rules, messages),
+            [rules] => (rules, ""),
+            ref s => return Err(anyhow!("Expected 2 sections, found {}", s.len())),
+        };
+        let rules =
+            parse_lines::<RuleLine>(rules).map_err(|e| ParseError::locate(e, input, rules))?;
+        let messages = messages.lines().map(|s| s.to_string()).collect_vec();
+
+        if log_enabled!(Level::Debug) {
+            debug!("rules ({}):", rules.len());
+            let rulemap = rules.iter().map(|r| (r.index, &r.rule)).collect();
+            for rule in rules.iter().sorted_by_key(|r| r.index) {
+                debug!(" - {:?} == {}", rule, rule.rule.to_regex_string(&rulemap));
+            }
+            debug!("messages ({}):", messages.len());
+            for msg in messages.iter() {
+                debug!(" - {:?}", msg);
+            }
+        }
+
+        Ok((rules, messages))
+    }
+
+    fn part_one((rules, messages): &Self::Input) -> Result<Self::AnswerOne> {
+        info!("Solving part one...");
+        Ok(solve_part_one(rules, messages))
+    }
+
+    fn part_two((rules, messages): &Self::Input) -> Result<Self::AnswerTwo> {
+        info!("Solving part two...");
+        Ok(solve_part_two(rules, messages))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_sample_part_one() {
+        let input = fs::read_to_string("data/sample.txt").unwrap();
+        let (rules, messages) = Day19::parse(&input).unwrap();
+        let result = solve_part_one(&rules, &messages);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_sample2_part_two() {
+        let input = fs::read_to_string("data/sample2.txt").unwrap();
+        let (rules, messages) = Day19::parse(&input).unwrap();
+        let result = solve_part_two(&rules, &messages);
+        assert_eq!(result, 12);
+    }
+}
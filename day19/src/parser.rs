@@ -5,6 +5,8 @@ use nom::combinator::{map, map_res};
 use nom::sequence::{delimited, tuple};
 use nom::Finish;
 
+use aoc_common::ParseError;
+
 use crate::rule::RuleLine;
 
 use super::rule::Rule;
@@ -12,34 +14,37 @@ use nom::multi::separated_list1;
 
 type Parsed<'a, T> = nom::IResult<&'a str, T>;
 
-fn index(i: &str) -> Parsed<usize> {
+fn index(i: &str) -> Parsed<'_, usize> {
     map_res(digit1, |x: &str| x.parse::<usize>())(i)
 }
 
-fn string(i: &str) -> Parsed<String> {
+fn string(i: &str) -> Parsed<'_, String> {
     let (i, s) = delimited(char('"'), is_not("\""), char('"'))(i)?;
     Ok((i, s.to_string()))
 }
 
-fn rule_alt(i: &str) -> Parsed<Vec<Vec<usize>>> {
+fn rule_alt(i: &str) -> Parsed<'_, Vec<Vec<usize>>> {
     separated_list1(tag(" | "), separated_list1(space1, index))(i)
 }
 
-fn rule(i: &str) -> Parsed<Rule> {
-    alt((
-        map(string, |s| Rule::Match(s)),
-        map(rule_alt, |x| Rule::Alt(x)),
-    ))(i)
+fn rule(i: &str) -> Parsed<'_, Rule> {
+    alt((map(string, Rule::Match), map(rule_alt, Rule::Alt)))(i)
 }
 
-fn ruleline(i: &str) -> Parsed<RuleLine> {
+fn ruleline(i: &str) -> Parsed<'_, RuleLine> {
     map(tuple((index, tag(": "), rule)), |(index, _, rule)| {
         RuleLine { index, rule }
     })(i)
 }
 
-pub(crate) fn parse_ruleline(line: &str) -> RuleLine {
-    let (unparsed, value) = ruleline(line).finish().unwrap();
-    assert!(unparsed.is_empty());
-    value
+pub(crate) fn parse_ruleline(line: &str) -> Result<RuleLine, ParseError> {
+    match ruleline(line).finish() {
+        Ok(("", value)) => Ok(value),
+        Ok((unparsed, _)) => Err(ParseError::at(line, unparsed, "Unexpected input")),
+        Err(e) => Err(ParseError::at(
+            line,
+            e.input,
+            format!("Syntax error: expected {}", e.code.description()),
+        )),
+    }
 }
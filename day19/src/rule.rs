@@ -3,11 +3,12 @@ use std::str::FromStr;
 
 use anyhow::Error;
 use itertools::Itertools;
+use log::trace;
 
 use crate::parser::parse_ruleline;
 
 #[derive(Debug, Clone)]
-pub(crate) struct RuleLine {
+pub struct RuleLine {
     pub index: usize,
     pub rule: Rule,
 }
@@ -16,12 +17,12 @@ impl FromStr for RuleLine {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(parse_ruleline(s))
+        Ok(parse_ruleline(s)?)
     }
 }
 
 #[derive(Debug, Clone)]
-pub(crate) enum Rule {
+pub enum Rule {
     Match(String),
     Alt(Vec<Vec<usize>>),
 }
@@ -37,7 +38,7 @@ impl Rule {
         rulemap: &HashMap<usize, &Rule>,
         cache: &mut HashMap<usize, String>,
     ) -> String {
-        // println!("Calculating regex for {:?}...", self);
+        trace!("Calculating regex for {:?}...", self);
         use Rule::*;
         match self {
             Match(s) => s.clone(),
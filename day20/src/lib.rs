@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use log::{debug, info, trace};
+use once_cell_regex::regex;
+use tap::Pipe;
+
+use aoc_common::input::sections;
+use aoc_common::{ParseError, Solution};
+
+use crate::side::{Direction, Side};
+use crate::tile::Tile;
+
+mod side;
+mod tile;
+
+fn solve_part_one(tiles: &[Tile]) -> usize {
+    let sides = {
+        use Side::*;
+        [Top, Bot, Left, Right]
+    };
+    let mut compatible = HashSet::new();
+    let mut compatible_flipped = HashSet::new();
+
+    for (i, tile1) in tiles.iter().enumerate() {
+        for side1 in sides.iter().copied() {
+            let s1 = tile1.data_on_side(side1, Direction::Inner);
+            for tile2 in tiles.iter().skip(i + 1) {
+                for side2 in sides.iter().copied() {
+                    let s2 = tile2.data_on_side(side2, Direction::Outer);
+                    if s1 == s2 {
+                        compatible.insert(((tile1.id, side1), (tile2.id, side2)));
+                    }
+                    let s2_rev = tile2.data_on_side(side2, Direction::Inner);
+                    if s1 == s2_rev {
+                        compatible_flipped.insert(((tile1.id, side1), (tile2.id, side2)));
+                    }
+                }
+            }
+        }
+    }
+
+    debug!("Compatible:");
+    for item in compatible.iter() {
+        debug!(" - {:?}", item);
+    }
+    debug!("Compatible (flipped):");
+    for item in compatible_flipped.iter() {
+        debug!(" - {:?}", item);
+    }
+
+    let mut p = 1;
+    for tile in tiles.iter() {
+        let comp = tiles
+            .iter()
+            .filter(|t| {
+                compatible.iter().any(|&((a, _), (b, _))| {
+                    (a == tile.id && b == t.id) || (b == tile.id && a == t.id)
+                })
+            })
+            .collect_vec();
+        let comp_rev = tiles
+            .iter()
+            .filter(|t| {
+                compatible_flipped.iter().any(|&((a, _), (b, _))| {
+                    (a == tile.id && b == t.id) || (b == tile.id && a == t.id)
+                })
+            })
+            .collect_vec();
+        if comp.len() + comp_rev.len() != 2 {
+            continue;
+        }
+        p *= tile.id;
+        debug!(
+            "Compatible with tile {}: {:?} ++ {:?} == {} + {} = {}",
+            tile.id,
+            comp.iter().map(|t| t.id).collect_vec(),
+            comp_rev.iter().map(|t| t.id).collect_vec(),
+            comp.len(),
+            comp_rev.len(),
+            comp.len() + comp_rev.len()
+        );
+    }
+    info!("product of corners: {}", p);
+
+    p
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Tile>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = regex!(r"^Tile (\d+):$");
+        let mut tiles = Vec::new();
+
+        for section in sections(input) {
+            let mut lines = section.lines();
+            let header = lines.next().unwrap_or_default();
+            let id = re
+                .captures(header)
+                .ok_or_else(|| ParseError::at(input, header, "Expected `Tile <id>:`"))?
+                .pipe(|caps| caps[1].parse::<usize>())
+                .map_err(|e| ParseError::locate(e, input, header))?;
+            let data = lines.map(|line| line.chars().collect()).collect();
+            tiles.push(Tile::new(id, data));
+        }
+
+        Ok(tiles)
+    }
+
+    fn part_one(tiles: &Self::Input) -> Result<Self::AnswerOne> {
+        debug!("tiles (length = {}):", tiles.len());
+        for tile in tiles.iter() {
+            trace!("{}", tile);
+        }
+
+        info!("Solving part one...");
+        Ok(solve_part_one(tiles))
+    }
+
+    fn part_two(_tiles: &Self::Input) -> Result<Self::AnswerTwo> {
+        Err(anyhow!("Part two is not solved yet"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        // 1951    2311    3079
+        // 2729    1427    2473
+        // 2971    1489    1171
+        let input = fs::read_to_string("data/sample.txt").unwrap();
+        let tiles = Day20::parse(&input).unwrap();
+        let result = solve_part_one(&tiles);
+        // 1951 * 3079 * 2971 * 1171 = 20899048083289
+        assert_eq!(result, 20899048083289);
+    }
+}
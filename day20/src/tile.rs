@@ -49,9 +49,10 @@ impl Tile {
         }
     }
 
+    #[cfg(test)]
     pub fn from_multiline_str(id: usize, s: &str) -> Self {
         let data = s.lines().map(|line| line.chars().collect()).collect();
-        eprintln!("data = {:?}", data);
+        log::trace!("data = {:?}", data);
         Tile::new(id, data)
     }
 
@@ -0,0 +1,252 @@
+//! Benchmarking of parsing and solving phases for each day.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use aoc_common::Part;
+
+use crate::days::Day;
+
+/// Benchmarked phase of a solution.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    pub fn name(&self) -> String {
+        match self {
+            Phase::Parse => "parse".to_string(),
+            Phase::Solve(part) => part.to_string(),
+        }
+    }
+}
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let min = sorted[0];
+        let max = sorted[n - 1];
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let secs = sorted.iter().map(|d| d.as_secs_f64()).collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Stats {
+            min,
+            max,
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Timing statistics for a single phase of a single day.
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day: u32,
+    pub phase: Phase,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+/// Runs the given `parts` of the `day` on the `input` for the given number of `iterations`,
+/// measuring parsing and each part separately.
+///
+/// Parts that fail are reported on stderr and excluded from the measurements.
+pub fn bench(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Measurement>> {
+    if iterations == 0 {
+        return Err(anyhow!("Number of iterations must be positive"));
+    }
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples = vec![Vec::with_capacity(iterations); parts.len()];
+    let mut failed = vec![false; parts.len()];
+
+    for _ in 0..iterations {
+        let solved = (day.solve)(input, parts)?;
+        parse_samples.push(solved.parse_time);
+        for (i, answer) in solved.answers.iter().enumerate() {
+            if let Err(e) = &answer.value {
+                if !failed[i] {
+                    eprintln!("[day{:02}] [{}] error: {:#}", day.number, answer.part, e);
+                    failed[i] = true;
+                }
+            }
+            part_samples[i].push(answer.elapsed);
+        }
+    }
+
+    let mut measurements = vec![Measurement {
+        day: day.number,
+        phase: Phase::Parse,
+        iterations,
+        stats: Stats::from_samples(&parse_samples).unwrap(),
+    }];
+    for (i, &part) in parts.iter().enumerate() {
+        if failed[i] {
+            continue;
+        }
+        measurements.push(Measurement {
+            day: day.number,
+            phase: Phase::Solve(part),
+            iterations,
+            stats: Stats::from_samples(&part_samples[i]).unwrap(),
+        });
+    }
+
+    Ok(measurements)
+}
+
+/// Formats the duration using the most appropriate unit.
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.3} s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.3} ms", secs * 1e3)
+    } else if secs >= 1e-6 {
+        format!("{:.3} µs", secs * 1e6)
+    } else {
+        format!("{} ns", d.as_nanos())
+    }
+}
+
+pub fn render_table(measurements: &[Measurement]) -> String {
+    let mut s = String::new();
+    writeln!(
+        s,
+        "{:>3}  {:<6} {:>5} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "day", "phase", "iters", "min", "mean", "median", "max", "stddev"
+    )
+    .unwrap();
+    for m in measurements {
+        writeln!(
+            s,
+            "{:>3}  {:<6} {:>5} {:>12} {:>12} {:>12} {:>12} {:>12}",
+            format!("{:02}", m.day),
+            m.phase.name(),
+            m.iterations,
+            format_duration(m.stats.min),
+            format_duration(m.stats.mean),
+            format_duration(m.stats.median),
+            format_duration(m.stats.max),
+            format_duration(m.stats.stddev),
+        )
+        .unwrap();
+    }
+    s
+}
+
+/// Machine-readable benchmark record, with all timings in seconds.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u32,
+    phase: String,
+    iterations: usize,
+    min: f64,
+    mean: f64,
+    median: f64,
+    max: f64,
+    stddev: f64,
+}
+
+impl From<&Measurement> for Record {
+    fn from(m: &Measurement) -> Self {
+        Record {
+            day: m.day,
+            phase: m.phase.name(),
+            iterations: m.iterations,
+            min: m.stats.min.as_secs_f64(),
+            mean: m.stats.mean.as_secs_f64(),
+            median: m.stats.median.as_secs_f64(),
+            max: m.stats.max.as_secs_f64(),
+            stddev: m.stats.stddev.as_secs_f64(),
+        }
+    }
+}
+
+pub fn render_json(measurements: &[Measurement]) -> Result<String> {
+    let records = measurements.iter().map(Record::from).collect::<Vec<_>>();
+    Ok(serde_json::to_string_pretty(&records)?)
+}
+
+pub fn render_csv(measurements: &[Measurement]) -> String {
+    let mut s = String::new();
+    writeln!(s, "day,phase,iterations,min,mean,median,max,stddev").unwrap();
+    for m in measurements {
+        let r = Record::from(m);
+        writeln!(
+            s,
+            "{},{},{},{},{},{},{},{}",
+            r.day, r.phase, r.iterations, r.min, r.mean, r.median, r.max, r.stddev
+        )
+        .unwrap();
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_odd() {
+        let samples = [3, 1, 2]
+            .iter()
+            .map(|&x| Duration::from_secs(x))
+            .collect::<Vec<_>>();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_secs(1));
+        assert_eq!(stats.max, Duration::from_secs(3));
+        assert_eq!(stats.median, Duration::from_secs(2));
+        assert_eq!(stats.mean, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let samples = [4, 1, 2, 5]
+            .iter()
+            .map(|&x| Duration::from_secs(x))
+            .collect::<Vec<_>>();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.median, Duration::from_secs(3));
+        assert_eq!(stats.mean, Duration::from_secs(3));
+        assert!((stats.stddev.as_secs_f64() - 2.5f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+
+use aoc_common::{Part, Solved};
+
+pub type Solver = fn(&str, &[Part]) -> Result<Solved>;
+
+pub struct Day {
+    pub number: u32,
+    pub solve: Solver,
+}
+
+macro_rules! days {
+    ($($number:literal => $krate:ident :: $solution:ident),* $(,)?) => {
+        pub static DAYS: &[Day] = &[
+            $(Day {
+                number: $number,
+                solve: aoc_common::solve::<$krate::$solution>,
+            }),*
+        ];
+    };
+}
+
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+}
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,6 @@
+//! Workspace-wide runner for all days.
+
+pub mod bench;
+pub mod days;
+pub mod registry;
+pub mod report;
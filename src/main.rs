@@ -0,0 +1,255 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use clap::{Parser, Subcommand};
+
+use aoc::bench::{self, format_duration};
+use aoc::{days, report};
+use aoc_common::input::read_input;
+use aoc_common::logging;
+use aoc_common::{with_file, Part, Solved};
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "aoc",
+    about = "Runs Advent of Code solutions from the workspace"
+)]
+struct Cli {
+    /// Verbosity of the solvers: `quiet`, `info`, `debug` or `trace`,
+    /// optionally followed by per-day overrides, e.g. `quiet,day11=debug`
+    #[clap(short, long, global = true, default_value = "info")]
+    verbosity: logging::Filter,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solvers for the given day
+    Run {
+        /// Day number
+        day: u32,
+        /// Which part to solve: `1`, `2` or `both`
+        #[clap(short, long, default_value = "both")]
+        part: PartSelection,
+        /// Input file (`-` for stdin), defaults to `dayNN/data/input.txt`
+        #[clap(short, long)]
+        input: Option<String>,
+        /// Output format: `text` or `json`
+        #[clap(short, long, default_value = "text")]
+        format: RunFormat,
+    },
+    /// Benchmark parsing and solving for the given days (all days by default)
+    Bench {
+        /// Day numbers
+        days: Vec<u32>,
+        /// Which part to solve: `1`, `2` or `both`
+        #[clap(short, long, default_value = "both")]
+        part: PartSelection,
+        /// Input file (`-` for stdin), defaults to `dayNN/data/input.txt`
+        #[clap(short, long)]
+        input: Option<String>,
+        /// Number of iterations for each day
+        #[clap(short = 'n', long, default_value = "10")]
+        iterations: usize,
+        /// Output format: `table`, `json` or `csv`
+        #[clap(short, long, default_value = "table")]
+        format: OutputFormat,
+    },
+    /// List available days
+    List,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum PartSelection {
+    One,
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    fn parts(&self) -> &'static [Part] {
+        match self {
+            PartSelection::One => &[Part::One],
+            PartSelection::Two => &[Part::Two],
+            PartSelection::Both => &Part::ALL,
+        }
+    }
+}
+
+impl FromStr for PartSelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "one" => Ok(PartSelection::One),
+            "2" | "two" => Ok(PartSelection::Two),
+            "both" => Ok(PartSelection::Both),
+            _ => Err(anyhow!("Bad part `{}`, expected `1`, `2` or `both`", s)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum RunFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for RunFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(RunFormat::Text),
+            "json" => Ok(RunFormat::Json),
+            _ => Err(anyhow!("Bad format `{}`, expected `text` or `json`", s)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(anyhow!(
+                "Bad format `{}`, expected `table`, `json` or `csv`",
+                s
+            )),
+        }
+    }
+}
+
+fn default_input_path(day: u32) -> String {
+    format!("day{:02}/data/input.txt", day)
+}
+
+fn run(day: u32, part: PartSelection, input: Option<String>, format: RunFormat) -> Result<()> {
+    let solution = days::find(day).ok_or_else(|| anyhow!("Day {} is not solved", day))?;
+    let path = input.unwrap_or_else(|| default_input_path(day));
+    let input = read_input(&path)?;
+
+    if format == RunFormat::Text {
+        println!("=== Day {:02} ({})", day, path);
+    }
+    let solved = (solution.solve)(&input, part.parts()).map_err(|e| with_file(e, &path))?;
+    let failed = solved.answers.iter().any(|answer| answer.value.is_err());
+
+    match format {
+        RunFormat::Text => print_text(day, &solved),
+        RunFormat::Json => println!(
+            "{}",
+            report::render_json(&report::records(day, &path, &solved))?
+        ),
+    }
+
+    if failed {
+        Err(anyhow!("Some parts of day {} have failed", day))
+    } else {
+        Ok(())
+    }
+}
+
+fn print_text(day: u32, solved: &Solved) {
+    println!(
+        "[day{:02}] [parse] done in {}",
+        day,
+        format_duration(solved.parse_time)
+    );
+    for answer in solved.answers.iter() {
+        match &answer.value {
+            Ok(value) => println!(
+                "[day{:02}] [{}] {} (in {})",
+                day,
+                answer.part,
+                value,
+                format_duration(answer.elapsed)
+            ),
+            Err(e) => eprintln!("[day{:02}] [{}] error: {:#}", day, answer.part, e),
+        }
+    }
+}
+
+fn run_bench(
+    days: Vec<u32>,
+    part: PartSelection,
+    input: Option<String>,
+    iterations: usize,
+    format: OutputFormat,
+) -> Result<()> {
+    let days = if days.is_empty() {
+        days::DAYS.iter().collect::<Vec<_>>()
+    } else {
+        days.iter()
+            .map(|&d| days::find(d).ok_or_else(|| anyhow!("Day {} is not solved", d)))
+            .collect::<Result<_>>()?
+    };
+    if input.is_some() && days.len() != 1 {
+        return Err(anyhow!("Custom input can only be used with a single day"));
+    }
+
+    let mut measurements = Vec::new();
+    for day in days {
+        let path = input
+            .clone()
+            .unwrap_or_else(|| default_input_path(day.number));
+        let input = read_input(&path)?;
+        eprintln!(
+            "Benchmarking day {:02} ({}) with {} iteration(s)...",
+            day.number, path, iterations
+        );
+        let measured =
+            bench::bench(day, &input, part.parts(), iterations).map_err(|e| with_file(e, &path))?;
+        measurements.extend(measured);
+    }
+
+    let output = match format {
+        OutputFormat::Table => bench::render_table(&measurements),
+        OutputFormat::Json => bench::render_json(&measurements)?,
+        OutputFormat::Csv => bench::render_csv(&measurements),
+    };
+    print!("{}", output);
+    if format == OutputFormat::Json {
+        println!();
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    logging::init(cli.verbosity)?;
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input, format),
+        Command::Bench {
+            days,
+            part,
+            input,
+            iterations,
+            format,
+        } => run_bench(days, part, input, iterations, format),
+        Command::List => {
+            for day in days::DAYS {
+                println!("day{:02}", day.number);
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Registry of expected answers, stored in `answers.txt` in the workspace root.
+//!
+//! Note: this module is also included by `build.rs` in order to generate the test suite,
+//!  so it must only depend on `std`.
+
+use std::fmt;
+
+/// Single registered case: the expected `answer` for the `part` of the `day`
+/// when solved using the `input` file from `dayNN/data/`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Case {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub answer: String,
+    pub slow: bool,
+}
+
+impl Case {
+    /// Path to the input file, relative to the workspace root.
+    pub fn input_path(&self) -> String {
+        format!("day{:02}/data/{}", self.day, self.input)
+    }
+}
+
+#[derive(Debug)]
+pub struct RegistryError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "answers.txt:{}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+pub fn parse(s: &str) -> Result<Vec<Case>, RegistryError> {
+    let mut cases = Vec::new();
+
+    for (i, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = |message: String| RegistryError {
+            line: i + 1,
+            message,
+        };
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let (day, part, input, answer, slow) = match fields[..] {
+            [day, part, input, answer] => (day, part, input, answer, false),
+            [day, part, input, answer, "slow"] => (day, part, input, answer, true),
+            _ => return Err(error(format!("bad case `{}`", line))),
+        };
+        let day = day
+            .parse()
+            .map_err(|_| error(format!("bad day `{}`", day)))?;
+        let part = match part {
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(error(format!("bad part `{}`", part))),
+        };
+
+        cases.push(Case {
+            day,
+            part,
+            input: input.to_string(),
+            answer: answer.to_string(),
+            slow,
+        });
+    }
+
+    Ok(cases)
+}
@@ -0,0 +1,102 @@
+//! Machine-readable reports of solver runs.
+
+use anyhow::Result;
+use serde::Serialize;
+
+use aoc_common::Solved;
+
+/// Result of solving one part, with all timings in seconds.
+///
+/// Exactly one of `answer` and `error` is set.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_time: f64,
+    pub time: f64,
+}
+
+/// Builds a record for each part solved for the `day` on the `input` file.
+pub fn records(day: u32, input: &str, solved: &Solved) -> Vec<Record> {
+    solved
+        .answers
+        .iter()
+        .map(|answer| {
+            let (value, error) = match &answer.value {
+                Ok(value) => (Some(value.clone()), None),
+                Err(e) => (None, Some(format!("{:#}", e))),
+            };
+            Record {
+                day,
+                part: answer.part.number(),
+                input: input.to_string(),
+                answer: value,
+                error,
+                parse_time: solved.parse_time.as_secs_f64(),
+                time: answer.elapsed.as_secs_f64(),
+            }
+        })
+        .collect()
+}
+
+pub fn render_json(records: &[Record]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(records)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+
+    use aoc_common::{Answer, Part};
+
+    use super::*;
+
+    #[test]
+    fn test_records() {
+        let solved = Solved {
+            parse_time: Duration::from_millis(1),
+            answers: vec![
+                Answer {
+                    part: Part::One,
+                    value: Ok("42".to_string()),
+                    elapsed: Duration::from_millis(2),
+                },
+                Answer {
+                    part: Part::Two,
+                    value: Err(anyhow!("Not solved")),
+                    elapsed: Duration::from_millis(3),
+                },
+            ],
+        };
+        let records = records(7, "day07/data/input.txt", &solved);
+        let json = serde_json::to_value(&records).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "day": 7,
+                    "part": 1,
+                    "input": "day07/data/input.txt",
+                    "answer": "42",
+                    "error": null,
+                    "parse_time": 0.001,
+                    "time": 0.002,
+                },
+                {
+                    "day": 7,
+                    "part": 2,
+                    "input": "day07/data/input.txt",
+                    "answer": null,
+                    "error": "Not solved",
+                    "parse_time": 0.001,
+                    "time": 0.003,
+                },
+            ])
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! Regression tests for all cases registered in `answers.txt`.
+
+use std::fs;
+use std::path::Path;
+
+use aoc::days;
+use aoc_common::Part;
+
+fn check(day: u32, part: u32, input: &str, expected: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(format!("day{:02}", day))
+        .join("data")
+        .join(input);
+    let input = fs::read_to_string(&path).unwrap();
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => unreachable!(),
+    };
+    let solution = days::find(day).unwrap();
+    let solved = (solution.solve)(&input, &[part]).unwrap();
+    let answer = solved.answers[0].value.as_ref().unwrap();
+    assert_eq!(answer, expected);
+}
+
+include!(concat!(env!("OUT_DIR"), "/registry_tests.rs"));